@@ -0,0 +1,50 @@
+//! Macros for the `aoc` crate.
+//!
+//! See the documentation of [Parseable][macro@Parseable] and [parse_pattern!]
+//! for how to use them.
+
+use syn::{parse_macro_input, DeriveInput};
+
+mod parseable;
+mod pattern;
+
+/// Derive the `Parseable` trait.
+///
+/// Structs parse their fields in order. The following attributes are
+/// supported:
+/// * `#[parse(sep = ",")]` on a struct takes the next item and splits it into
+///   the items used for the fields by the given separator.
+/// * `#[parse(lit = "->")]` on a field expects the given literal before the
+///   field. Literals separated by whitespace are expected as separate items.
+///
+/// Enums may only have unit variants, each of which is parsed from a keyword.
+/// The keyword is the lowercase name of the variant unless it's specified
+/// with `#[parse("forward")]`.
+#[proc_macro_derive(Parseable, attributes(parse))]
+pub fn derive_parseable(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match parseable::expand(input) {
+        Ok(stream) => stream.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Match input against a pattern, like `parse_pattern!("{},{} -> {},{}", line)`.
+///
+/// The input is either a `&str` or a `Parser`. Each `{}` placeholder extends
+/// up until the next literal in the pattern, and is parsed as a `Parseable`.
+/// Literal braces are written as `{{` and `}}`.
+///
+/// This evaluates to a `Result` holding the value of the only placeholder, or
+/// a tuple if there are several. Errors indicate which literal or placeholder
+/// failed to match.
+#[proc_macro]
+pub fn parse_pattern(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as pattern::Input);
+
+    match pattern::expand(input) {
+        Ok(stream) => stream.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
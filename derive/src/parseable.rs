@@ -0,0 +1,232 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, Data, DeriveInput, Error, Fields, GenericParam, Lifetime, Lit, LitStr, Meta,
+    NestedMeta,
+};
+
+/// Attributes supported by the derive.
+#[derive(Default)]
+struct Attrs {
+    keyword: Option<LitStr>,
+    sep: Option<LitStr>,
+    lit: Vec<LitStr>,
+}
+
+impl Attrs {
+    fn parse(attrs: &[Attribute]) -> Result<Self, Error> {
+        let mut out = Self::default();
+
+        for attr in attrs {
+            if !attr.path.is_ident("parse") {
+                continue;
+            }
+
+            let list = match attr.parse_meta()? {
+                Meta::List(list) => list,
+                meta => return Err(Error::new(meta.span(), "expected `#[parse(...)]`")),
+            };
+
+            for nested in list.nested {
+                match nested {
+                    NestedMeta::Lit(Lit::Str(s)) => {
+                        out.keyword = Some(s);
+                    }
+                    NestedMeta::Meta(Meta::NameValue(nv)) => {
+                        let s = match nv.lit {
+                            Lit::Str(s) => s,
+                            lit => return Err(Error::new(lit.span(), "expected a string")),
+                        };
+
+                        if nv.path.is_ident("sep") {
+                            out.sep = Some(s);
+                        } else if nv.path.is_ident("lit") {
+                            out.lit.push(s);
+                        } else {
+                            return Err(Error::new(nv.path.span(), "unsupported attribute"));
+                        }
+                    }
+                    nested => return Err(Error::new(nested.span(), "unsupported attribute")),
+                }
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// Only allow the given attributes to be set.
+    fn only(&self, keyword: bool, sep: bool, lit: bool) -> Result<(), Error> {
+        let unsupported = [
+            (keyword, self.keyword.as_ref()),
+            (sep, self.sep.as_ref()),
+            (lit, self.lit.first()),
+        ];
+
+        for (allowed, s) in unsupported {
+            if let (false, Some(s)) = (allowed, s) {
+                return Err(Error::new(s.span(), "attribute not supported here"));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) fn expand(mut input: DeriveInput) -> Result<TokenStream, Error> {
+    let attrs = Attrs::parse(&input.attrs)?;
+
+    // Use the lifetime of the struct if it has one, so that fields can borrow
+    // from the input.
+    let lifetimes = input.generics.lifetimes().collect::<Vec<_>>();
+
+    let (lt, extra) = match lifetimes.as_slice() {
+        [] => (Lifetime::new("'de", Span::call_site()), true),
+        [def] => (def.lifetime.clone(), false),
+        [_, second, ..] => {
+            return Err(Error::new(
+                second.span(),
+                "only a single lifetime is supported",
+            ))
+        }
+    };
+
+    let body = match &input.data {
+        Data::Struct(st) => {
+            attrs.only(false, true, false)?;
+            expand_struct(&attrs, &st.fields)?
+        }
+        Data::Enum(en) => {
+            attrs.only(false, false, false)?;
+            expand_enum(en.variants.iter())?
+        }
+        Data::Union(un) => {
+            return Err(Error::new(
+                un.union_token.span(),
+                "unions are not supported",
+            ))
+        }
+    };
+
+    for param in &mut input.generics.params {
+        if let GenericParam::Type(ty) = param {
+            ty.bounds.push(syn::parse_quote!(::aoc::Parseable<#lt>));
+        }
+    }
+
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut generics = input.generics.clone();
+
+    if extra {
+        generics.params.insert(0, syn::parse_quote!(#lt));
+    }
+
+    let (impl_generics, _, _) = generics.split_for_impl();
+    let ident = &input.ident;
+
+    Ok(quote! {
+        impl #impl_generics ::aoc::Parseable<#lt> for #ident #ty_generics #where_clause {
+            fn parse(p: &mut ::aoc::Parser<#lt>) -> ::core::result::Result<Self, ::aoc::ParseError> {
+                #body
+            }
+        }
+    })
+}
+
+fn expand_struct(attrs: &Attrs, fields: &Fields) -> Result<TokenStream, Error> {
+    let mut stmts = Vec::new();
+    let mut vars = Vec::new();
+
+    for (n, field) in fields.iter().enumerate() {
+        let field_attrs = Attrs::parse(&field.attrs)?;
+        field_attrs.only(false, false, true)?;
+
+        for lit in &field_attrs.lit {
+            for word in lit.value().split_whitespace() {
+                stmts.push(quote!(::aoc::Parser::expect_item(p, #word)?;));
+            }
+        }
+
+        let var = format_ident!("f{}", n);
+        let ty = &field.ty;
+        stmts.push(quote!(let #var = ::aoc::Parser::parse::<#ty>(p)?;));
+        vars.push(var);
+    }
+
+    let construct = match fields {
+        Fields::Named(..) => {
+            let idents = fields.iter().map(|f| &f.ident);
+            quote!(Self { #(#idents: #vars),* })
+        }
+        Fields::Unnamed(..) => quote!(Self(#(#vars),*)),
+        Fields::Unit => quote!(Self),
+    };
+
+    let split = attrs.sep.as_ref().map(|sep| {
+        quote! {
+            let mut p = ::aoc::Parser::split(p, #sep)?;
+            let p = &mut p;
+        }
+    });
+
+    // The split item has to be used up entirely by the fields.
+    let finish = attrs.sep.as_ref().map(|_| {
+        quote! {
+            if !::aoc::Parser::is_empty(p) {
+                return ::core::result::Result::Err(::aoc::Parser::error_at(
+                    p,
+                    ::aoc::Parser::as_str(p).trim(),
+                    ::aoc::ParseErrorKind::TrailingInput,
+                ));
+            }
+        }
+    });
+
+    Ok(quote! {
+        #split
+        #(#stmts)*
+        #finish
+        ::core::result::Result::Ok(#construct)
+    })
+}
+
+fn expand_enum<'a>(variants: impl Iterator<Item = &'a syn::Variant>) -> Result<TokenStream, Error> {
+    let mut arms = Vec::new();
+    let mut keywords = Vec::new();
+
+    for variant in variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new(
+                variant.fields.span(),
+                "only unit variants are supported",
+            ));
+        }
+
+        let attrs = Attrs::parse(&variant.attrs)?;
+        attrs.only(true, false, false)?;
+
+        let keyword = match attrs.keyword {
+            Some(keyword) => keyword.value(),
+            None => variant.ident.to_string().to_lowercase(),
+        };
+
+        let ident = &variant.ident;
+        arms.push(quote!(#keyword => ::core::result::Result::Ok(Self::#ident),));
+        keywords.push(format!("`{}`", keyword));
+    }
+
+    let expected = format!("one of {}", keywords.join(", "));
+
+    Ok(quote! {
+        let item = ::aoc::Parser::item(p)?;
+
+        match item {
+            #(#arms)*
+            _ => ::core::result::Result::Err(::aoc::Parser::error_at(
+                p,
+                item,
+                ::aoc::ParseErrorKind::Expected(::std::string::String::from(#expected)),
+            )),
+        }
+    })
+}
@@ -0,0 +1,117 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{Error, Expr, LitStr, Token};
+
+/// The input to `parse_pattern!`.
+pub(crate) struct Input {
+    pattern: LitStr,
+    expr: Expr,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let pattern = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let expr = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self { pattern, expr })
+    }
+}
+
+/// A piece of a pattern.
+enum Piece {
+    Literal(String),
+    Placeholder,
+}
+
+/// Split a pattern into pieces.
+fn pieces(pattern: &LitStr) -> Result<Vec<Piece>, Error> {
+    let mut out = Vec::new();
+    let mut literal = String::new();
+    let value = pattern.value();
+    let mut it = value.chars().peekable();
+
+    while let Some(c) = it.next() {
+        match (c, it.peek()) {
+            ('{', Some('{')) | ('}', Some('}')) => {
+                it.next();
+                literal.push(c);
+            }
+            ('{', Some('}')) => {
+                it.next();
+
+                if !literal.is_empty() {
+                    out.push(Piece::Literal(std::mem::take(&mut literal)));
+                } else if let Some(Piece::Placeholder) = out.last() {
+                    return Err(Error::new(
+                        pattern.span(),
+                        "placeholders must be separated by a literal",
+                    ));
+                }
+
+                out.push(Piece::Placeholder);
+            }
+            ('{', _) | ('}', _) => {
+                return Err(Error::new(
+                    pattern.span(),
+                    "unsupported brace in pattern, use `{}` for placeholders or `{{` and `}}` for literal braces",
+                ));
+            }
+            _ => {
+                literal.push(c);
+            }
+        }
+    }
+
+    if !literal.is_empty() {
+        out.push(Piece::Literal(literal));
+    }
+
+    Ok(out)
+}
+
+pub(crate) fn expand(input: Input) -> Result<TokenStream, Error> {
+    let Input { pattern, expr } = input;
+    let pieces = pieces(&pattern)?;
+
+    let mut stmts = Vec::new();
+    let mut vars = Vec::new();
+
+    for (n, piece) in pieces.iter().enumerate() {
+        match piece {
+            Piece::Literal(literal) => {
+                stmts.push(quote!(pattern.literal(#literal)?;));
+            }
+            Piece::Placeholder => {
+                let until = match pieces.get(n + 1) {
+                    Some(Piece::Literal(literal)) => quote!(::core::option::Option::Some(#literal)),
+                    _ => quote!(::core::option::Option::None),
+                };
+
+                let var = format_ident!("v{}", vars.len());
+                let index = vars.len() + 1;
+                stmts.push(quote!(let #var = pattern.placeholder(#index, #until)?;));
+                vars.push(var);
+            }
+        }
+    }
+
+    let value = match vars.as_slice() {
+        [var] => quote!(#var),
+        vars => quote!((#(#vars),*)),
+    };
+
+    Ok(quote! {{
+        let mut pattern = ::aoc::Pattern::new(
+            #pattern,
+            ::core::convert::Into::<::aoc::Parser<'_>>::into(#expr),
+        );
+
+        (move || -> ::core::result::Result<_, ::aoc::ParseError> {
+            #(#stmts)*
+            pattern.finish()?;
+            ::core::result::Result::Ok(#value)
+        })()
+    }})
+}
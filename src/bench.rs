@@ -0,0 +1,148 @@
+//! Statistics collected when benchmarking days.
+
+use std::fmt::{self, Write};
+use std::time::Duration;
+
+use crate::days::Timings;
+
+/// Statistics over a collection of samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    /// Calculate statistics over the given samples, returns `None` if there are
+    /// no samples or too many to average.
+    pub fn new(samples: &mut [Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        samples.sort();
+
+        let n = samples.len();
+
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+
+        let mean = samples.iter().sum::<Duration>() / u32::try_from(n).ok()?;
+
+        Some(Self {
+            min: samples[0],
+            median,
+            mean,
+        })
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:.2?}, median {:.2?}, mean {:.2?}",
+            self.min, self.median, self.mean
+        )
+    }
+}
+
+/// The result of benchmarking a single day.
+#[derive(Debug, Clone)]
+pub struct Bench {
+    pub profile: String,
+    pub day: u32,
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+    pub total: Stats,
+}
+
+impl Bench {
+    /// Collect statistics from the timings of repeated runs of a day. Returns
+    /// `None` if there are no timings.
+    pub fn new(profile: &str, day: u32, timings: &[Timings]) -> Option<Self> {
+        let stats = |f: fn(&Timings) -> Duration| {
+            Stats::new(&mut timings.iter().map(f).collect::<Vec<_>>())
+        };
+
+        Some(Self {
+            profile: profile.to_owned(),
+            day,
+            runs: timings.len(),
+            parse: stats(|t| t.parse)?,
+            part1: stats(|t| t.part1)?,
+            part2: stats(|t| t.part2)?,
+            total: stats(Timings::total)?,
+        })
+    }
+}
+
+/// Serialize benchmark results as JSON, with all durations in nanoseconds.
+pub fn to_json(benches: &[Bench]) -> String {
+    let mut out = String::new();
+    out.push_str("[\n");
+
+    for (n, b) in benches.iter().enumerate() {
+        let _ = write!(
+            out,
+            "  {{\"profile\": {}, \"day\": {}, \"runs\": {}, \"parse\": {}, \"part1\": {}, \"part2\": {}, \"total\": {}}}",
+            JsonString(&b.profile),
+            b.day,
+            b.runs,
+            Json(&b.parse),
+            Json(&b.part1),
+            Json(&b.part2),
+            Json(&b.total),
+        );
+
+        if n + 1 < benches.len() {
+            out.push(',');
+        }
+
+        out.push('\n');
+    }
+
+    out.push_str("]\n");
+    out
+}
+
+/// Helper to write statistics as a JSON object.
+struct Json<'a>(&'a Stats);
+
+impl fmt::Display for Json<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{{\"min_ns\": {}, \"median_ns\": {}, \"mean_ns\": {}}}",
+            self.0.min.as_nanos(),
+            self.0.median.as_nanos(),
+            self.0.mean.as_nanos()
+        )
+    }
+}
+
+/// Helper to write a value as a JSON string.
+struct JsonString<'a>(&'a str);
+
+impl fmt::Display for JsonString<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"")?;
+
+        for c in self.0.chars() {
+            match c {
+                '"' => write!(f, "\\\"")?,
+                '\\' => write!(f, "\\\\")?,
+                c if c < ' ' => write!(f, "\\u{:04x}", u32::from(c))?,
+                c => write!(f, "{}", c)?,
+            }
+        }
+
+        write!(f, "\"")
+    }
+}
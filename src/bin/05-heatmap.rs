@@ -0,0 +1,109 @@
+//! Export a heatmap of how many vent lines of day 5 cover each point.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use anyhow::{anyhow, bail, Context, Result};
+use aoc::days::day05::Day05;
+use aoc::{Loader, Solution, Source};
+
+const USAGE: &str =
+    "usage: 05-heatmap [--no-diagonals] [--block <n>] [--image-block <n>] [--pgm <path>] [--ppm <path>] [<input>]
+
+Prints the heatmap as text, where each character covers a block of points and
+shows the sum of their counts.
+
+Options:
+  --no-diagonals       skip diagonal lines, like part 1 does
+  --block <n>          the number of points along each side of a block of text,
+                       defaults to fitting the map in 100 columns and rows
+  --image-block <n>    the number of points along each side of a pixel, defaults
+                       to fitting the map in 1000 by 1000 pixels
+  --pgm <path>         write the heatmap as a greyscale PGM image
+  --ppm <path>         write the heatmap as a colour PPM image";
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {:?}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<()> {
+    let mut diagonals = true;
+    let mut block = None;
+    let mut image_block = None;
+    let mut pgm = None;
+    let mut ppm = None;
+    let mut loader = Loader::new();
+
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .ok_or_else(|| anyhow!("missing argument to {}", name))
+        };
+
+        match arg.as_str() {
+            "--no-diagonals" => diagonals = false,
+            "--block" => block = Some(value("--block")?.parse().context("bad --block")?),
+            "--image-block" => {
+                image_block = Some(
+                    value("--image-block")?
+                        .parse()
+                        .context("bad --image-block")?,
+                )
+            }
+            "--pgm" => pgm = Some(PathBuf::from(value("--pgm")?)),
+            "--ppm" => ppm = Some(PathBuf::from(value("--ppm")?)),
+            "help" | "--help" | "-h" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            _ if arg.starts_with("--") => bail!("unsupported option `{}`\n\n{}", arg, USAGE),
+            _ => loader = loader.with_source(Source::from_arg(arg)),
+        }
+    }
+
+    let input = loader.load("05.txt")?;
+    let day = Day05::parse(&input)?;
+    let (width, height) = day.size(diagonals);
+    let side = width.max(height);
+
+    if pgm.is_some() || ppm.is_some() {
+        let heatmap = day.heatmap(
+            diagonals,
+            block_size("--image-block", image_block, side, 1000)?,
+        );
+
+        if let Some(path) = &pgm {
+            fs::write(path, heatmap.to_pgm())
+                .with_context(|| anyhow!("failed to write `{}`", path.display()))?;
+        }
+
+        if let Some(path) = &ppm {
+            fs::write(path, heatmap.to_ppm())
+                .with_context(|| anyhow!("failed to write `{}`", path.display()))?;
+        }
+    }
+
+    let heatmap = day.heatmap(diagonals, block_size("--block", block, side, 100)?);
+    print!("{}", heatmap.to_ascii());
+    Ok(())
+}
+
+/// Get the configured block size, or the smallest one which fits the given
+/// number of points into the given number of blocks.
+fn block_size(name: &str, block: Option<usize>, side: usize, fit: usize) -> Result<usize> {
+    match block {
+        Some(0) => bail!("bad {}: must be non-zero", name),
+        Some(block) => Ok(block),
+        None => Ok(side.div_ceil(fit).max(1)),
+    }
+}
@@ -0,0 +1,244 @@
+//! A bingo engine for boards of any size.
+//!
+//! Numbers are drawn one at a time and marked on every board they appear on.
+//! A board wins once a full row or column, and optionally a full diagonal, is
+//! marked. Playing reports every board in the order that it won.
+
+use std::collections::HashMap;
+
+use crate::{Grid, ParseError, ParseErrorKind, Parseable, Parser};
+
+/// A bingo board.
+///
+/// A board can be parsed from rows of whitespace separated numbers, like the
+/// following. Parsing stops at the first blank line.
+///
+/// ```text
+/// 22 13 17
+///  8  2 23
+/// 21  9 14
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Board {
+    grid: Grid<u64>,
+}
+
+impl Board {
+    /// Construct a board from a grid of numbers.
+    pub fn new(grid: Grid<u64>) -> Self {
+        Self { grid }
+    }
+
+    /// Access the numbers on the board.
+    pub fn grid(&self) -> &Grid<u64> {
+        &self.grid
+    }
+}
+
+impl Parseable<'_> for Board {
+    fn parse(p: &mut Parser<'_>) -> Result<Self, ParseError> {
+        let mut data = Vec::new();
+        let mut width = None;
+
+        while let Some(mut row) = p.next_line() {
+            let line = row.as_str();
+
+            if line.is_empty() {
+                break;
+            }
+
+            let numbers = row.parse::<Vec<u64>>()?;
+
+            match width {
+                None => width = Some(numbers.len()),
+                Some(width) if width != numbers.len() => {
+                    let kind = ParseErrorKind::Expected(format!("a row of {} numbers", width));
+                    return Err(row.error_at(line, kind));
+                }
+                _ => {}
+            }
+
+            data.extend(numbers);
+        }
+
+        let width = match width {
+            Some(width) => width,
+            None => {
+                let kind = ParseErrorKind::Expected("a bingo board".to_owned());
+                return Err(p.error(kind));
+            }
+        };
+
+        Ok(Self::new(Grid::from_vec(width, data)))
+    }
+}
+
+/// A board winning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Win {
+    /// The index of the board which won.
+    pub board: usize,
+    /// The index of the draw which won.
+    pub draw: usize,
+    /// The number which was drawn.
+    pub number: u64,
+    /// The sum of all unmarked numbers on the board multiplied by the drawn
+    /// number, or `None` if it doesn't fit in a `u64`.
+    pub score: Option<u64>,
+}
+
+/// A game of bingo over a collection of boards.
+#[derive(Debug, Clone)]
+pub struct Bingo {
+    boards: Vec<Board>,
+    diagonals: bool,
+    /// Every cell each number appears in.
+    index: HashMap<u64, Vec<Cell>>,
+}
+
+/// A cell on a board.
+#[derive(Debug, Clone, Copy)]
+struct Cell {
+    board: usize,
+    pos: (usize, usize),
+}
+
+impl Bingo {
+    /// Construct a game over the given boards.
+    pub fn new(boards: Vec<Board>) -> Self {
+        let mut index = HashMap::<_, Vec<_>>::new();
+
+        for (b, board) in boards.iter().enumerate() {
+            for (pos, n) in board.grid.iter() {
+                index.entry(*n).or_default().push(Cell { board: b, pos });
+            }
+        }
+
+        Self {
+            boards,
+            diagonals: false,
+            index,
+        }
+    }
+
+    /// Set if a full diagonal wins. Diagonals only count on square boards.
+    pub fn with_diagonals(mut self, diagonals: bool) -> Self {
+        self.diagonals = diagonals;
+        self
+    }
+
+    /// The boards being played.
+    pub fn boards(&self) -> &[Board] {
+        &self.boards
+    }
+
+    /// Play the given draws, returning every board which won in the order
+    /// that they won.
+    ///
+    /// Boards stop being played once they've won, and boards which win on the
+    /// same draw are ordered by index.
+    pub fn play(&self, draws: &[u64]) -> Vec<Win> {
+        let mut states = self
+            .boards
+            .iter()
+            .map(|board| State::new(&board.grid))
+            .collect::<Vec<_>>();
+
+        let mut wins = Vec::new();
+
+        for (draw, &number) in draws.iter().enumerate() {
+            if wins.len() == self.boards.len() {
+                break;
+            }
+
+            let mut winners = Vec::new();
+
+            // NB: a board is only marked as won once every cell of the draw
+            // is marked, so that the score accounts for duplicate numbers.
+            for cell in self.index.get(&number).into_iter().flatten() {
+                let state = &mut states[cell.board];
+
+                if !state.won && state.mark(cell.pos, self.diagonals) {
+                    winners.push(cell.board);
+                }
+            }
+
+            winners.sort_unstable();
+            winners.dedup();
+
+            for b in winners {
+                let state = &mut states[b];
+                state.won = true;
+
+                wins.push(Win {
+                    board: b,
+                    draw,
+                    number,
+                    score: state
+                        .unmarked(&self.boards[b].grid)
+                        .and_then(|sum| sum.checked_mul(number)),
+                });
+            }
+        }
+
+        wins
+    }
+}
+
+/// The state of a single board while playing.
+struct State {
+    marked: Grid<bool>,
+    rows: Vec<usize>,
+    columns: Vec<usize>,
+    /// Marked cells on the main and anti diagonals.
+    diagonals: [usize; 2],
+    won: bool,
+}
+
+impl State {
+    fn new(grid: &Grid<u64>) -> Self {
+        Self {
+            marked: Grid::filled(grid.width(), grid.height(), false),
+            rows: vec![0; grid.height()],
+            columns: vec![0; grid.width()],
+            diagonals: [0; 2],
+            won: false,
+        }
+    }
+
+    /// Mark the given position, returning `true` if the board won.
+    fn mark(&mut self, (x, y): (usize, usize), diagonals: bool) -> bool {
+        if std::mem::replace(&mut self.marked[(x, y)], true) {
+            return false;
+        }
+
+        let (width, height) = (self.marked.width(), self.marked.height());
+
+        self.rows[y] += 1;
+        self.columns[x] += 1;
+
+        let mut won = self.rows[y] == width || self.columns[x] == height;
+
+        if diagonals && width == height {
+            if x == y {
+                self.diagonals[0] += 1;
+                won |= self.diagonals[0] == width;
+            }
+
+            if x + y + 1 == width {
+                self.diagonals[1] += 1;
+                won |= self.diagonals[1] == width;
+            }
+        }
+
+        won
+    }
+
+    /// Sum all unmarked numbers, or `None` if the sum overflows.
+    fn unmarked(&self, grid: &Grid<u64>) -> Option<u64> {
+        grid.values()
+            .zip(self.marked.values())
+            .filter(|(_, marked)| !**marked)
+            .try_fold(0u64, |sum, (n, _)| sum.checked_add(*n))
+    }
+}
@@ -0,0 +1,235 @@
+//! Reading and writing of values which are packed at the bit level, most
+//! significant bit first.
+
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use thiserror::Error;
+
+/// The widest value that can be read or written at a time.
+pub const MAX_WIDTH: u32 = u128::BITS;
+
+/// An error raised while reading bits.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum BitsError {
+    #[error("invalid hex digit {c:?} at index {index}")]
+    InvalidHex { c: char, index: usize },
+    #[error("cannot read {bits} bits at position {pos}, only {remaining} remain")]
+    Eof {
+        bits: usize,
+        pos: usize,
+        remaining: usize,
+    },
+    #[error("cannot read {0} bits, at most {MAX_WIDTH} can be read at a time")]
+    TooWide(u32),
+}
+
+/// Reads values from bytes at the bit level.
+#[derive(Debug, Clone)]
+pub struct BitReader<'a> {
+    bytes: Cow<'a, [u8]>,
+    /// The number of bits available.
+    len: usize,
+    /// The bit cursor.
+    pos: usize,
+}
+
+impl BitReader<'static> {
+    /// Construct a reader over hex input, like `D2FE28`, where each digit
+    /// contributes 4 bits. Surrounding whitespace is ignored.
+    pub fn from_hex(input: &str) -> Result<Self, BitsError> {
+        let input = input.trim();
+        let mut bytes = Vec::with_capacity(input.len().div_ceil(2));
+
+        for (index, c) in input.chars().enumerate() {
+            let n = match c.to_digit(16) {
+                Some(n) => n as u8,
+                None => return Err(BitsError::InvalidHex { c, index }),
+            };
+
+            if index % 2 == 0 {
+                bytes.push(n << 4);
+            } else if let Some(b) = bytes.last_mut() {
+                *b |= n;
+            }
+        }
+
+        Ok(Self {
+            bytes: Cow::Owned(bytes),
+            len: input.len() * 4,
+            pos: 0,
+        })
+    }
+}
+
+impl<'a> BitReader<'a> {
+    /// Construct a reader over the given bytes.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes: Cow::Borrowed(bytes),
+            len: bytes.len() * 8,
+            pos: 0,
+        }
+    }
+
+    /// The position of the cursor in bits.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// The total number of bits.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// The number of bits remaining to be read.
+    pub fn remaining(&self) -> usize {
+        self.len - self.pos
+    }
+
+    /// Test if all bits have been read.
+    pub fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Ensure that the given number of bits can be read.
+    fn ensure(&self, bits: usize) -> Result<(), BitsError> {
+        if bits > self.remaining() {
+            return Err(BitsError::Eof {
+                bits,
+                pos: self.pos,
+                remaining: self.remaining(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Read a single bit.
+    pub fn read_bit(&mut self) -> Result<bool, BitsError> {
+        self.ensure(1)?;
+        let b = self.bytes[self.pos / 8] >> (7 - self.pos % 8) & 1;
+        self.pos += 1;
+        Ok(b == 1)
+    }
+
+    /// Read a value which is `bits` wide, where `bits` is at most
+    /// [MAX_WIDTH].
+    pub fn read(&mut self, bits: u32) -> Result<u128, BitsError> {
+        if bits > MAX_WIDTH {
+            return Err(BitsError::TooWide(bits));
+        }
+
+        self.ensure(bits as usize)?;
+
+        let mut out = 0u128;
+        let mut bits = bits as usize;
+
+        while bits > 0 {
+            let available = 8 - self.pos % 8;
+            let take = available.min(bits);
+            let byte = self.bytes[self.pos / 8] >> (available - take);
+            let mask = (1u16 << take) - 1;
+
+            // NB: shifting by the full width of a u128 overflows.
+            out = out.checked_shl(take as u32).unwrap_or(0) | (byte as u16 & mask) as u128;
+
+            self.pos += take;
+            bits -= take;
+        }
+
+        Ok(out)
+    }
+
+    /// Skip over the given number of bits.
+    pub fn skip(&mut self, bits: usize) -> Result<(), BitsError> {
+        self.ensure(bits)?;
+        self.pos += bits;
+        Ok(())
+    }
+}
+
+/// Writes values at the bit level, which can later be read back using a
+/// [BitReader].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BitWriter {
+    bytes: Vec<u8>,
+    /// The number of bits written.
+    len: usize,
+}
+
+impl BitWriter {
+    /// Construct a new empty writer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of bits written.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Test if no bits have been written.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Write a single bit.
+    pub fn write_bit(&mut self, bit: bool) {
+        if self.len.is_multiple_of(8) {
+            self.bytes.push(0);
+        }
+
+        if bit {
+            if let Some(b) = self.bytes.last_mut() {
+                *b |= 1 << (7 - self.len % 8);
+            }
+        }
+
+        self.len += 1;
+    }
+
+    /// Write the lowest `bits` bits of the given value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is larger than [MAX_WIDTH].
+    pub fn write(&mut self, value: u128, bits: u32) {
+        assert!(
+            bits <= MAX_WIDTH,
+            "cannot write more than {} bits",
+            MAX_WIDTH
+        );
+
+        for n in (0..bits).rev() {
+            self.write_bit(value >> n & 1 == 1);
+        }
+    }
+
+    /// Get the written bytes, where the last byte is padded with zeros.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Construct a reader over the written bits.
+    pub fn reader(&self) -> BitReader<'_> {
+        BitReader {
+            bytes: Cow::Borrowed(&self.bytes),
+            len: self.len,
+            pos: 0,
+        }
+    }
+
+    /// Format the written bits as hex, where the last digit is padded with
+    /// zeros.
+    pub fn to_hex(&self) -> String {
+        let mut out = String::with_capacity(self.len.div_ceil(4));
+
+        for b in &self.bytes {
+            let _ = write!(out, "{:02X}", b);
+        }
+
+        out.truncate(self.len.div_ceil(4));
+        out
+    }
+}
@@ -0,0 +1,262 @@
+use std::fmt;
+
+use crate::{ParseError, ParseErrorKind, Parseable, Parser};
+
+const WORD: usize = u64::BITS as usize;
+
+/// A vector of bits with a dynamic width.
+///
+/// Bits are indexed from the most significant one, so the bit vector parsed
+/// from `10110` has bit `0` set and converts into the number `22`. Bit vectors
+/// of the same width order the same way as the numbers they represent.
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BitVec {
+    /// Bits packed most significant first, where unused bits in the last word
+    /// are always zero.
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitVec {
+    /// Construct a new empty bit vector.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Construct a bit vector of the given width with all bits unset.
+    pub fn zeros(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(WORD)],
+            len,
+        }
+    }
+
+    /// The number of bits.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Test if there are no bits.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the bit at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn get(&self, index: usize) -> bool {
+        assert!(index < self.len, "bit {} out of bounds", index);
+        self.words[index / WORD] >> (WORD - 1 - index % WORD) & 1 == 1
+    }
+
+    /// Set the bit at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn set(&mut self, index: usize, bit: bool) {
+        assert!(index < self.len, "bit {} out of bounds", index);
+        let mask = 1 << (WORD - 1 - index % WORD);
+
+        if bit {
+            self.words[index / WORD] |= mask;
+        } else {
+            self.words[index / WORD] &= !mask;
+        }
+    }
+
+    /// Push a bit to the end of the vector.
+    pub fn push(&mut self, bit: bool) {
+        if self.len.is_multiple_of(WORD) {
+            self.words.push(0);
+        }
+
+        self.len += 1;
+        self.set(self.len - 1, bit);
+    }
+
+    /// Iterate over all bits.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).map(move |n| self.get(n))
+    }
+
+    /// Count the number of set bits.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Construct a bit vector of the same width with every bit flipped.
+    pub fn not(&self) -> Self {
+        let mut out = Self {
+            words: self.words.iter().map(|w| !w).collect(),
+            len: self.len,
+        };
+
+        out.clear_unused();
+        out
+    }
+
+    /// Convert into a number, or `None` if it doesn't fit in a `u64`.
+    pub fn to_u64(&self) -> Option<u64> {
+        let mut out = 0u64;
+
+        for bit in self.iter() {
+            out = out.checked_mul(2)? | u64::from(bit);
+        }
+
+        Some(out)
+    }
+
+    /// Make sure that unused bits in the last word are zero.
+    fn clear_unused(&mut self) {
+        let used = self.len % WORD;
+
+        if let (Some(last), true) = (self.words.last_mut(), used != 0) {
+            *last &= !(u64::MAX >> used);
+        }
+    }
+}
+
+impl FromIterator<bool> for BitVec {
+    fn from_iter<T: IntoIterator<Item = bool>>(iter: T) -> Self {
+        let mut out = Self::new();
+
+        for bit in iter {
+            out.push(bit);
+        }
+
+        out
+    }
+}
+
+impl fmt::Display for BitVec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for bit in self.iter() {
+            if bit { "1" } else { "0" }.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses an item of binary digits, like `10110`.
+impl Parseable<'_> for BitVec {
+    fn parse(p: &mut Parser<'_>) -> Result<Self, ParseError> {
+        let item = p.item()?;
+        let mut out = Self::new();
+
+        for (n, c) in item.char_indices() {
+            match c {
+                '0' => out.push(false),
+                '1' => out.push(true),
+                _ => {
+                    let kind = ParseErrorKind::Expected("a binary digit".to_owned());
+                    return Err(p.error_at(&item[n..n + c.len_utf8()], kind));
+                }
+            }
+        }
+
+        Ok(out)
+    }
+}
+
+/// Counts the number of set bits in each column over a collection of bit
+/// vectors.
+///
+/// Counting is word-parallel: the counts for 64 columns at a time are kept as
+/// bit-sliced binary counters, where each plane holds one bit of the count of
+/// every column.
+#[derive(Debug, Default, Clone)]
+pub struct ColumnCounts {
+    /// Counter planes for each word, least significant plane first.
+    planes: Vec<Vec<u64>>,
+    /// The number of columns.
+    len: usize,
+    /// The number of bit vectors counted.
+    total: usize,
+}
+
+impl ColumnCounts {
+    /// Construct new empty column counts.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the bits of the given bit vector to the counts.
+    pub fn add(&mut self, bits: &BitVec) {
+        if self.planes.len() < bits.words.len() {
+            self.planes.resize_with(bits.words.len(), Vec::new);
+        }
+
+        for (planes, &word) in self.planes.iter_mut().zip(&bits.words) {
+            let mut carry = word;
+
+            for plane in planes.iter_mut() {
+                if carry == 0 {
+                    break;
+                }
+
+                let next = *plane & carry;
+                *plane ^= carry;
+                carry = next;
+            }
+
+            if carry != 0 {
+                planes.push(carry);
+            }
+        }
+
+        self.len = self.len.max(bits.len());
+        self.total += 1;
+    }
+
+    /// The number of columns counted, which is the width of the widest bit
+    /// vector.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Test if nothing has been counted.
+    pub fn is_empty(&self) -> bool {
+        self.total == 0
+    }
+
+    /// The number of bit vectors counted.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// The number of set bits in the given column.
+    pub fn ones(&self, column: usize) -> usize {
+        let planes = match self.planes.get(column / WORD) {
+            Some(planes) => planes,
+            None => return 0,
+        };
+
+        let shift = WORD - 1 - column % WORD;
+
+        planes
+            .iter()
+            .enumerate()
+            .map(|(k, plane)| ((plane >> shift & 1) as usize) << k)
+            .sum()
+    }
+
+    /// Construct a bit vector where each column is set if it has at least as
+    /// many set as unset bits.
+    pub fn most_common(&self) -> BitVec {
+        (0..self.len)
+            .map(|n| self.ones(n) * 2 >= self.total)
+            .collect()
+    }
+}
+
+impl<'a> Extend<&'a BitVec> for ColumnCounts {
+    fn extend<T: IntoIterator<Item = &'a BitVec>>(&mut self, iter: T) {
+        for bits in iter {
+            self.add(bits);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use std::convert::Infallible;
+
+use anyhow::Result;
+
+use crate::Solution;
+
+/// Sonar sweep depth measurements.
+pub struct Day01 {
+    lines: Vec<u32>,
+}
+
+impl Solution for Day01 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self> {
+        let lines = crate::lines::<u32>(input).collect::<Result<_, _>>()?;
+        Ok(Self { lines })
+    }
+
+    fn part1(&self) -> Result<u32> {
+        let depths = self.lines.iter().copied().map(Ok::<_, Infallible>);
+        Ok(increases::<1, _, _>(depths)?)
+    }
+
+    fn part2(&self) -> Result<u32> {
+        let depths = self.lines.iter().copied().map(Ok::<_, Infallible>);
+        Ok(increases::<3, _, _>(depths)?)
+    }
+}
+
+/// Count the number of times the sum of a sliding window of `N` depths
+/// increases.
+///
+/// Since two neighbouring windows share all but one depth, this only has to
+/// compare each depth with the one `N` steps before it. So only the last `N`
+/// depths are kept in memory, which allows it to consume a stream of depths
+/// like the one produced by [read_lines][crate::read_lines()].
+pub fn increases<const N: usize, I, E>(depths: I) -> Result<u32, E>
+where
+    I: IntoIterator<Item = Result<u32, E>>,
+{
+    let mut window = [0u32; N];
+    let mut count = 0;
+
+    for (n, depth) in depths.into_iter().enumerate() {
+        let depth = depth?;
+        let last = std::mem::replace(&mut window[n % N], depth);
+
+        if n >= N && depth > last {
+            count += 1;
+        }
+    }
+
+    Ok(count)
+}
@@ -0,0 +1,66 @@
+use anyhow::Result;
+
+use crate::{Parseable, Solution};
+
+#[derive(Debug, Clone, Copy, Parseable)]
+enum Command {
+    Forward,
+    Down,
+    Up,
+}
+
+/// Submarine commands.
+pub struct Day02 {
+    lines: Vec<(Command, u32)>,
+}
+
+impl Solution for Day02 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self> {
+        let lines = crate::lines::<(Command, u32)>(input).collect::<Result<_, _>>()?;
+        Ok(Self { lines })
+    }
+
+    fn part1(&self) -> Result<u32> {
+        let mut a = (0, 0);
+
+        for (command, d) in self.lines.iter().copied() {
+            match command {
+                Command::Forward => {
+                    a.0 += d;
+                }
+                Command::Down => {
+                    a.1 += d;
+                }
+                Command::Up => {
+                    a.1 -= d;
+                }
+            }
+        }
+
+        Ok(a.0 * a.1)
+    }
+
+    fn part2(&self) -> Result<u32> {
+        let mut b = (0, 0, 0);
+
+        for (command, d) in self.lines.iter().copied() {
+            match command {
+                Command::Forward => {
+                    b.0 += d;
+                    b.1 += b.2 * d;
+                }
+                Command::Down => {
+                    b.2 += d;
+                }
+                Command::Up => {
+                    b.2 -= d;
+                }
+            }
+        }
+
+        Ok(b.0 * b.1)
+    }
+}
@@ -0,0 +1,89 @@
+use anyhow::{anyhow, bail, Result};
+
+use crate::{BitVec, ColumnCounts, Solution};
+
+/// Diagnostic report.
+pub struct Day03 {
+    /// Diagnostic lines, sorted so that lines sharing a prefix are adjacent.
+    lines: Vec<BitVec>,
+}
+
+impl Solution for Day03 {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self> {
+        let mut lines = crate::lines::<BitVec>(input).collect::<Result<Vec<_>, _>>()?;
+
+        if let Some(first) = lines.first() {
+            let width = first.len();
+
+            if let Some(bits) = lines.iter().find(|b| b.len() != width) {
+                bail!("expected lines of width {}, but got `{}`", width, bits);
+            }
+        }
+
+        lines.sort();
+        Ok(Self { lines })
+    }
+
+    fn part1(&self) -> Result<u64> {
+        let mut counts = ColumnCounts::new();
+        counts.extend(&self.lines);
+
+        let gamma = counts.most_common();
+        let epsilon = gamma.not();
+
+        product(&gamma, &epsilon)
+    }
+
+    fn part2(&self) -> Result<u64> {
+        let a = find(&self.lines, true);
+        let b = find(&self.lines, false);
+
+        let (a, b) = match (a, b) {
+            (Some(a), Some(b)) => (a, b),
+            _ => bail!("no answer found"),
+        };
+
+        product(a, b)
+    }
+}
+
+/// Multiply two ratings, failing if they are too wide.
+fn product(a: &BitVec, b: &BitVec) -> Result<u64> {
+    let too_wide = || anyhow!("{} bits are too wide for the answer", a.len());
+    let a = a.to_u64().ok_or_else(too_wide)?;
+    let b = b.to_u64().ok_or_else(too_wide)?;
+    a.checked_mul(b).ok_or_else(|| anyhow!("answer overflows"))
+}
+
+/// Find a rating among the sorted lines.
+///
+/// Since the lines are sorted, the ones which remain after filtering by each
+/// bit are always a contiguous range. Every round splits that range where the
+/// current bit goes from unset to set instead of copying the lines.
+fn find(lines: &[BitVec], most: bool) -> Option<&BitVec> {
+    let mut lines = lines;
+    let mut n = 0;
+
+    while lines.len() > 1 {
+        // Identical lines can't be told apart.
+        if n == lines[0].len() {
+            return None;
+        }
+
+        let (zeros, ones) = lines.split_at(lines.partition_point(|b| !b.get(n)));
+
+        let keep_ones = if most {
+            ones.len() >= zeros.len()
+        } else {
+            ones.len() < zeros.len()
+        };
+
+        lines = if keep_ones { ones } else { zeros };
+        n += 1;
+    }
+
+    lines.first()
+}
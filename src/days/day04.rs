@@ -0,0 +1,43 @@
+use anyhow::{anyhow, Result};
+
+use crate::bingo::{Bingo, Board};
+use crate::{Delimited, Parser, Solution};
+
+/// Bingo numbers and boards.
+pub struct Day04 {
+    nums: Vec<u64>,
+    bingo: Bingo,
+}
+
+impl Solution for Day04 {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self> {
+        let mut p = Parser::new(input);
+
+        let nums = p.section()?.parse::<Delimited<u64, ','>>()?.into_vec();
+
+        let boards = p
+            .sections()
+            .map(|mut section| section.parse::<Board>())
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            nums,
+            bingo: Bingo::new(boards),
+        })
+    }
+
+    fn part1(&self) -> Result<u64> {
+        let wins = self.bingo.play(&self.nums);
+        let win = wins.first().ok_or_else(|| anyhow!("no winning board"))?;
+        win.score.ok_or_else(|| anyhow!("score overflows"))
+    }
+
+    fn part2(&self) -> Result<u64> {
+        let wins = self.bingo.play(&self.nums);
+        let win = wins.last().ok_or_else(|| anyhow!("no winning board"))?;
+        win.score.ok_or_else(|| anyhow!("score overflows"))
+    }
+}
@@ -0,0 +1,91 @@
+use anyhow::{bail, Result};
+
+use crate::heatmap::Heatmap;
+use crate::segments::{self, Segment};
+use crate::{Parseable, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Parseable)]
+#[parse(sep = ",")]
+struct Point {
+    x: isize,
+    y: isize,
+}
+
+#[derive(Debug, Clone, Copy, Parseable)]
+struct Line {
+    from: Point,
+    #[parse(lit = "->")]
+    to: Point,
+}
+
+/// Lines of hydrothermal vents.
+pub struct Day05 {
+    segments: Vec<Segment>,
+}
+
+impl Solution for Day05 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        let mut segments = Vec::new();
+
+        for line in crate::lines::<Line>(input) {
+            let Line { from, to } = line?;
+
+            let segment = match Segment::new((from.x, from.y), (to.x, to.y)) {
+                Some(segment) => segment,
+                None => bail!(
+                    "line {},{} -> {},{} is neither horizontal, vertical nor diagonal",
+                    from.x,
+                    from.y,
+                    to.x,
+                    to.y
+                ),
+            };
+
+            segments.push(segment);
+        }
+
+        Ok(Self { segments })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(segments::overlaps_by_line(&self.segments(false)))
+    }
+
+    fn part2(&self) -> Result<usize> {
+        Ok(segments::overlaps_by_line(&self.segments(true)))
+    }
+}
+
+impl Day05 {
+    /// Get the vent lines, optionally including diagonal ones.
+    fn segments(&self, diagonals: bool) -> Vec<Segment> {
+        self.segments
+            .iter()
+            .filter(|s| diagonals || !s.is_diagonal())
+            .copied()
+            .collect()
+    }
+
+    /// The width and height of the area covered by vent lines, optionally
+    /// including diagonal ones.
+    pub fn size(&self, diagonals: bool) -> (usize, usize) {
+        match segments::bounds(&self.segments(diagonals)) {
+            Some((min, max)) => ((max.0 - min.0 + 1) as usize, (max.1 - min.1 + 1) as usize),
+            None => (0, 0),
+        }
+    }
+
+    /// Construct a heatmap of how many vent lines cover each square block of
+    /// points, optionally including diagonal ones.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `block` is zero.
+    pub fn heatmap(&self, diagonals: bool, block: usize) -> Heatmap {
+        let (_, grid) = segments::count_map(&self.segments(diagonals), block);
+        Heatmap::new(grid)
+    }
+}
@@ -0,0 +1,34 @@
+use anyhow::Result;
+
+use crate::lanternfish::Model;
+use crate::matrix::Checked;
+use crate::{Delimited, Parser, Solution};
+
+/// Ages of lanternfish.
+pub struct Day06 {
+    model: Model,
+    school: Vec<u64>,
+}
+
+impl Solution for Day06 {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self> {
+        let line = Parser::new(input)
+            .line()?
+            .parse::<Delimited<usize, ','>>()?;
+
+        let model = Model::default();
+        let school = model.school(line)?;
+        Ok(Self { model, school })
+    }
+
+    fn part1(&self) -> Result<u64> {
+        Ok(self.model.count(&self.school, 80, &Checked)?)
+    }
+
+    fn part2(&self) -> Result<u64> {
+        Ok(self.model.count(&self.school, 256, &Checked)?)
+    }
+}
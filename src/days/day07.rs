@@ -0,0 +1,62 @@
+use anyhow::{anyhow, Result};
+
+use crate::optimize;
+use crate::{Delimited, Parser, Solution};
+
+/// Positions of crab submarines.
+pub struct Day07 {
+    pos: Vec<i64>,
+}
+
+impl Solution for Day07 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self> {
+        let mut pos = Parser::new(input)
+            .line()?
+            .parse::<Delimited<i64, ','>>()?
+            .into_vec();
+
+        pos.sort();
+        Ok(Self { pos })
+    }
+
+    fn part1(&self) -> Result<i64> {
+        // The median minimizes the sum of distances.
+        let at = optimize::median(&self.pos).ok_or_else(|| anyhow!("no crabs"))?;
+        Ok(linear(&self.pos, at))
+    }
+
+    fn part2(&self) -> Result<i64> {
+        // The cost is minimized within half a step of the mean, so only the
+        // positions around it need to be checked.
+        let mean = optimize::mean(&self.pos).ok_or_else(|| anyhow!("no crabs"))?;
+        let (_, cost) = optimize::scan(mean - 1..=mean + 2, |at| triangular(&self.pos, at))
+            .ok_or_else(|| anyhow!("no crabs"))?;
+        Ok(cost)
+    }
+}
+
+/// The fuel used when every step costs one.
+pub fn linear(input: &[i64], at: i64) -> i64 {
+    let mut sum = 0;
+
+    for n in input {
+        sum += (at - *n).abs();
+    }
+
+    sum
+}
+
+/// The fuel used when every step costs one more than the previous one.
+pub fn triangular(input: &[i64], at: i64) -> i64 {
+    let mut sum = 0;
+
+    for n in input {
+        let n = (at - *n).abs();
+        sum += (n * (n + 1)) / 2;
+    }
+
+    sum
+}
@@ -0,0 +1,60 @@
+use anyhow::{anyhow, Result};
+
+use crate::{search, Grid, Parser, Solution};
+
+/// Height map of the cave floor.
+pub struct Day09 {
+    map: Grid<u32>,
+}
+
+impl Solution for Day09 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self> {
+        let map = Parser::new(input).parse()?;
+        Ok(Self { map })
+    }
+
+    fn part1(&self) -> Result<u32> {
+        let mut part1 = 0;
+
+        for ((x, y), &current) in self.map.iter() {
+            if self.map.neighbours4(x, y).all(|n| self.map[n] > current) {
+                part1 += current + 1;
+            }
+        }
+
+        Ok(part1)
+    }
+
+    fn part2(&self) -> Result<u32> {
+        let mut seen = self.map.map(|&h| h == 9);
+        let mut basins = Vec::new();
+
+        for n in self.map.positions() {
+            if seen[n] {
+                continue;
+            }
+
+            let basin = search::reachable(n, |&(x, y)| {
+                self.map.neighbours4(x, y).filter(|&n| self.map[n] != 9)
+            });
+
+            for &n in &basin {
+                seen[n] = true;
+            }
+
+            basins.push(basin.len() as u32);
+        }
+
+        basins.sort_unstable();
+
+        basins
+            .into_iter()
+            .rev()
+            .take(3)
+            .try_fold(1, u32::checked_mul)
+            .ok_or_else(|| anyhow!("basin product overflow"))
+    }
+}
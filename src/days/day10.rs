@@ -0,0 +1,133 @@
+use anyhow::{anyhow, bail, Result};
+
+use crate::{ParseErrorKind, Parser, Solution};
+
+#[derive(Debug, Clone, Copy)]
+enum Chunk {
+    Paren,
+    Bracket,
+    Brace,
+    Angle,
+}
+
+/// The outcome of checking a single line.
+#[derive(Debug, Clone, Copy)]
+enum Status {
+    /// The line is corrupted, with the syntax error score of the first illegal
+    /// character.
+    Corrupted(u64),
+    /// The line is incomplete, with the score of the completion string.
+    Incomplete(u64),
+}
+
+/// Lines of the navigation subsystem.
+pub struct Day10 {
+    lines: Vec<Status>,
+}
+
+impl Solution for Day10 {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self> {
+        let mut p = Parser::new(input);
+        let mut lines = Vec::new();
+
+        while let Some(line) = p.next_line() {
+            lines.push(check(line)?);
+        }
+
+        Ok(Self { lines })
+    }
+
+    fn part1(&self) -> Result<u64> {
+        let mut part1 = 0;
+
+        for status in &self.lines {
+            if let Status::Corrupted(s) = status {
+                part1 += s;
+            }
+        }
+
+        Ok(part1)
+    }
+
+    fn part2(&self) -> Result<u64> {
+        let mut part2 = Vec::new();
+
+        for status in &self.lines {
+            if let Status::Incomplete(s) = status {
+                part2.push(*s);
+            }
+        }
+
+        part2.sort();
+
+        if part2.len() % 2 != 1 {
+            bail!("expected an odd number of incomplete lines");
+        }
+
+        let middle = part2.len() / 2;
+        Ok(part2[middle])
+    }
+}
+
+fn check(mut p: Parser<'_>) -> Result<Status> {
+    let mut stack = Vec::new();
+
+    while let Some(c) = p.peek_char() {
+        let chunk = match c {
+            '(' => Chunk::Paren,
+            '[' => Chunk::Bracket,
+            '{' => Chunk::Brace,
+            '<' => Chunk::Angle,
+            o => {
+                let top = stack.pop().ok_or_else(|| anyhow!("corrupted line"))?;
+
+                match (top, o) {
+                    (Chunk::Paren, ')') => (),
+                    (Chunk::Bracket, ']') => (),
+                    (Chunk::Brace, '}') => (),
+                    (Chunk::Angle, '>') => (),
+                    (_, c) => {
+                        let s = match c {
+                            ')' => 3,
+                            ']' => 57,
+                            '}' => 1197,
+                            '>' => 25137,
+                            _ => {
+                                let kind = ParseErrorKind::Expected("a chunk delimiter".to_owned());
+                                return Err(p.error_at(p.peek(1), kind).into());
+                            }
+                        };
+
+                        return Ok(Status::Corrupted(s));
+                    }
+                }
+
+                p.next_char();
+                continue;
+            }
+        };
+
+        p.next_char();
+        stack.push(chunk);
+    }
+
+    stack.reverse();
+
+    let mut p2 = 0u64;
+
+    for chunk in stack {
+        p2 *= 5;
+
+        p2 += match chunk {
+            Chunk::Paren => 1,
+            Chunk::Bracket => 2,
+            Chunk::Brace => 3,
+            Chunk::Angle => 4,
+        };
+    }
+
+    Ok(Status::Incomplete(p2))
+}
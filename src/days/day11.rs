@@ -0,0 +1,74 @@
+use std::collections::VecDeque;
+
+use anyhow::{bail, Result};
+
+use crate::{Grid, Parser, Solution};
+
+/// Energy levels of dumbo octopuses.
+pub struct Day11 {
+    grid: Grid<u8>,
+}
+
+impl Solution for Day11 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        let grid = Parser::new(input).parse()?;
+        Ok(Self { grid })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        let mut grid = self.grid.clone();
+        let mut part1 = 0;
+
+        for _ in 0..100 {
+            part1 += step(&mut grid);
+        }
+
+        Ok(part1)
+    }
+
+    fn part2(&self) -> Result<usize> {
+        let mut grid = self.grid.clone();
+
+        for n in 1.. {
+            if step(&mut grid) == grid.len() {
+                return Ok(n);
+            }
+        }
+
+        bail!("octopuses never synchronize")
+    }
+}
+
+/// Perform a single step, returning the number of octopuses which flashed.
+fn step(grid: &mut Grid<u8>) -> usize {
+    let mut bump = VecDeque::new();
+    bump.extend(grid.positions());
+
+    while let Some((x, y)) = bump.pop_back() {
+        let v = &mut grid[(x, y)];
+
+        if *v < 9 {
+            *v += 1;
+            continue;
+        }
+
+        if *v != 10 {
+            *v = 10;
+            bump.extend(grid.neighbours8(x, y));
+        }
+    }
+
+    let mut count = 0;
+
+    for v in grid.values_mut() {
+        if *v == 10 {
+            *v = 0;
+            count += 1;
+        }
+    }
+
+    count
+}
@@ -0,0 +1,146 @@
+use anyhow::{anyhow, bail, Result};
+
+use crate::{parse_pattern, Interner, Parser, Solution, Symbol};
+
+/// Cave system connections.
+pub struct Day12 {
+    names: Interner,
+    /// Caves connected to each cave, indexed by symbol.
+    edges: Vec<Vec<Symbol>>,
+    /// If each cave is big, indexed by symbol.
+    big: Vec<bool>,
+    start: Symbol,
+    end: Symbol,
+}
+
+impl Day12 {
+    /// List every path through the cave system, like `start,A,b,end`.
+    ///
+    /// If `twice` is set, a single small cave may be visited twice.
+    pub fn paths(&self, twice: bool) -> Vec<String> {
+        let mut paths = Vec::new();
+
+        self.walk(twice, |path| {
+            let names = path.iter().map(|s| self.names.resolve(*s));
+            paths.push(names.collect::<Vec<_>>().join(","));
+        });
+
+        paths
+    }
+
+    /// Walk every path through the cave system, calling `visit` with each
+    /// complete path.
+    fn walk<F>(&self, twice: bool, mut visit: F)
+    where
+        F: FnMut(&[Symbol]),
+    {
+        let mut visited = vec![false; self.names.len()];
+        visited[self.start.index()] = true;
+
+        let mut path = vec![self.start];
+        self.walk_from(&mut visited, &mut path, twice, &mut visit);
+    }
+
+    fn walk_from<F>(&self, visited: &mut [bool], path: &mut Vec<Symbol>, twice: bool, visit: &mut F)
+    where
+        F: FnMut(&[Symbol]),
+    {
+        let cur = match path.last() {
+            Some(cur) => *cur,
+            None => return,
+        };
+
+        if cur == self.end {
+            visit(path);
+            return;
+        }
+
+        for &next in &self.edges[cur.index()] {
+            if next == self.start {
+                continue;
+            }
+
+            let index = next.index();
+            path.push(next);
+
+            if self.big[index] {
+                self.walk_from(visited, path, twice, visit);
+            } else if !visited[index] {
+                visited[index] = true;
+                self.walk_from(visited, path, twice, visit);
+                visited[index] = false;
+            } else if twice {
+                self.walk_from(visited, path, false, visit);
+            }
+
+            path.pop();
+        }
+    }
+
+    fn count(&self, twice: bool) -> usize {
+        let mut count = 0;
+        self.walk(twice, |_| count += 1);
+        count
+    }
+}
+
+impl Solution for Day12 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        let mut p = Parser::new(input);
+
+        let mut names = Interner::new();
+        let mut edges = Vec::<Vec<Symbol>>::new();
+
+        while let Some(line) = p.next_line() {
+            let (from, to): (&str, &str) = parse_pattern!("{}-{}", line)?;
+
+            if from == to {
+                bail!("cave `{}` is connected to itself", from);
+            }
+
+            let from = names.intern(from);
+            let to = names.intern(to);
+            edges.resize_with(names.len(), Vec::new);
+
+            edges[from.index()].push(to);
+            edges[to.index()].push(from);
+        }
+
+        let start = names
+            .get("start")
+            .ok_or_else(|| anyhow!("missing start cave"))?;
+        let end = names
+            .get("end")
+            .ok_or_else(|| anyhow!("missing end cave"))?;
+
+        let big = names
+            .iter()
+            .map(|(_, name)| name.chars().all(char::is_uppercase))
+            .collect::<Vec<_>>();
+
+        for (symbol, name) in names.iter() {
+            if big[symbol.index()] && edges[symbol.index()].iter().any(|n| big[n.index()]) {
+                bail!("big cave `{}` is connected to another big cave", name);
+            }
+        }
+
+        Ok(Self {
+            names,
+            edges,
+            big,
+            start,
+            end,
+        })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(self.count(false))
+    }
+
+    fn part2(&self) -> Result<usize> {
+        Ok(self.count(true))
+    }
+}
@@ -0,0 +1,95 @@
+use anyhow::{anyhow, Result};
+
+use crate::{ocr, Grid, Parseable, Parser, Sections, Solution};
+
+#[derive(Debug, Clone, Copy, Parseable)]
+#[parse(sep = ",")]
+struct Dot {
+    x: usize,
+    y: usize,
+}
+
+#[derive(Debug, Clone, Copy, Parseable)]
+enum Axis {
+    X,
+    Y,
+}
+
+#[derive(Debug, Clone, Copy, Parseable)]
+#[parse(sep = "=")]
+struct Inst {
+    axis: Axis,
+    d: usize,
+}
+
+/// A folding instruction, like `fold along y=7`.
+#[derive(Debug, Clone, Copy, Parseable)]
+struct Fold(#[parse(lit = "fold along")] Inst);
+
+/// Fold the page according to the given instruction, where dots past the fold
+/// are mirrored onto the remaining page.
+///
+/// The dots don't have to reach the fold line, in which case the folded page
+/// is padded with blank space.
+fn fold(page: &Grid<bool>, inst: Inst) -> Grid<bool> {
+    let d = inst.d;
+
+    match inst.axis {
+        Axis::X => Grid::from_fn(d, page.height(), |x, y| {
+            page.get(x, y).copied().unwrap_or_default()
+                || page.get(2 * d - x, y).copied().unwrap_or_default()
+        }),
+        Axis::Y => Grid::from_fn(page.width(), d, |x, y| {
+            page.get(x, y).copied().unwrap_or_default()
+                || page.get(x, 2 * d - y).copied().unwrap_or_default()
+        }),
+    }
+}
+
+/// Transparent paper and folding instructions.
+pub struct Day13 {
+    page: Grid<bool>,
+    insts: Vec<Inst>,
+}
+
+impl Solution for Day13 {
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self> {
+        let Sections((dots, folds)) =
+            Parser::new(input).parse::<Sections<(Vec<Dot>, Vec<Fold>)>>()?;
+
+        let w = dots.iter().map(|d| d.x + 1).max().unwrap_or_default();
+        let h = dots.iter().map(|d| d.y + 1).max().unwrap_or_default();
+
+        let mut page = Grid::filled(w, h, false);
+
+        for Dot { x, y } in dots {
+            page[(x, y)] = true;
+        }
+
+        let insts = folds.into_iter().map(|Fold(inst)| inst).collect();
+        Ok(Self { page, insts })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        let inst = *self
+            .insts
+            .first()
+            .ok_or_else(|| anyhow!("missing fold instructions"))?;
+
+        let page = fold(&self.page, inst);
+        Ok(page.values().filter(|dot| **dot).count())
+    }
+
+    fn part2(&self) -> Result<String> {
+        let mut page = self.page.clone();
+
+        for inst in &self.insts {
+            page = fold(&page, *inst);
+        }
+
+        Ok(ocr::recognize(&page)?)
+    }
+}
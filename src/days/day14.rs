@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::{parse_pattern, Parser, Solution};
+
+/// Polymer template and pair insertion rules.
+pub struct Day14 {
+    template: Vec<char>,
+    rules: HashMap<[char; 2], char>,
+}
+
+impl Solution for Day14 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        let mut p = Parser::new(input);
+
+        let template = p.section()?.into_str().chars().collect::<Vec<_>>();
+
+        let mut rules = HashMap::new();
+        let mut section = p.section()?;
+
+        while let Some(line) = section.next_line() {
+            let (m, to): (&str, char) = parse_pattern!("{} -> {}", line)?;
+
+            let m = match *m.chars().collect::<Vec<_>>() {
+                [a, b] => [a, b],
+                _ => bail!("expected a pair of elements, but got `{}`", m),
+            };
+
+            rules.insert(m, to);
+        }
+
+        Ok(Self { template, rules })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        solve2(&self.template, 10, &self.rules)
+    }
+
+    fn part2(&self) -> Result<usize> {
+        solve2(&self.template, 40, &self.rules)
+    }
+}
+
+fn solve2(chain: &[char], n: usize, rules: &HashMap<[char; 2], char>) -> Result<usize> {
+    let mut counts = Counts::default();
+    let mut memo = HashMap::new();
+
+    for w in chain.windows(2) {
+        if let &[a, b] = w {
+            merge_to(&mut counts, inner(a, b, rules, n, &mut memo));
+        }
+    }
+
+    if let Some(c) = chain.last() {
+        counts[as_index(*c)] += 1;
+    }
+
+    let mut values = counts.into_iter().filter(|n| *n != 0).collect::<Vec<_>>();
+    values.sort();
+
+    let first = *values.first().ok_or_else(|| anyhow!("missing first"))?;
+    let last = *values.last().ok_or_else(|| anyhow!("missing last"))?;
+    return Ok(last - first);
+
+    type Counts = [usize; 26];
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    struct Key(char, char, usize);
+
+    fn inner(
+        a: char,
+        b: char,
+        rules: &HashMap<[char; 2], char>,
+        n: usize,
+        memo: &mut HashMap<Key, Counts>,
+    ) -> Counts {
+        if n == 0 {
+            let mut counts = Counts::default();
+            counts[as_index(a)] = 1;
+            return counts;
+        }
+
+        let key = Key(a, b, n);
+
+        if let Some(counts) = memo.get(&key) {
+            return *counts;
+        }
+
+        let counts = if let Some(c) = rules.get(&[a, b]).copied() {
+            let mut counts = inner(a, c, rules, n - 1, memo);
+            merge_to(&mut counts, inner(c, b, rules, n - 1, memo));
+            counts
+        } else {
+            let mut counts = Counts::default();
+            counts[as_index(a)] = 1;
+            counts
+        };
+
+        memo.insert(key, counts);
+        counts
+    }
+
+    fn merge_to(to: &mut Counts, from: Counts) {
+        for (n, v) in from.into_iter().enumerate() {
+            to[n] += v;
+        }
+    }
+
+    fn as_index(c: char) -> usize {
+        c as usize - 'A' as usize
+    }
+}
@@ -0,0 +1,51 @@
+use anyhow::{anyhow, Result};
+
+use crate::{search, Grid, Parser, Solution};
+
+/// Risk levels of the cavern.
+pub struct Day15 {
+    map: Grid<usize>,
+}
+
+impl Solution for Day15 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        let map = Parser::new(input).parse()?;
+        Ok(Self { map })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        lowest_risk(&self.map).ok_or_else(|| anyhow!("no path found"))
+    }
+
+    fn part2(&self) -> Result<usize> {
+        lowest_risk(&grow(&self.map, 5)).ok_or_else(|| anyhow!("no path found"))
+    }
+}
+
+/// Find the lowest total risk of a path from the top left to the bottom right
+/// of the map.
+fn lowest_risk(map: &Grid<usize>) -> Option<usize> {
+    let target = (map.width().checked_sub(1)?, map.height().checked_sub(1)?);
+
+    let found = search::dijkstra(
+        (0, 0),
+        |&(x, y)| map.neighbours4(x, y).map(|n| (n, map[n])),
+        |n| *n == target,
+    )?;
+
+    Some(found.cost)
+}
+
+/// Grow the map by the given factor, increasing the risk of each copy by its
+/// manhattan distance from the original.
+fn grow(map: &Grid<usize>, factor: usize) -> Grid<usize> {
+    let (w, h) = (map.width(), map.height());
+
+    Grid::from_fn(w * factor, h * factor, |x, y| {
+        let manhattan = x / w + y / h;
+        (map[(x % w, y % h)] - 1 + manhattan) % 9 + 1
+    })
+}
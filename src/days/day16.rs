@@ -0,0 +1,124 @@
+use anyhow::{anyhow, bail, Result};
+
+use crate::bits::BitReader;
+use crate::{Parser, Solution};
+
+#[derive(Default)]
+struct Extra {
+    part1: usize,
+}
+
+/// A BITS transmission.
+pub struct Day16 {
+    bits: BitReader<'static>,
+}
+
+impl Solution for Day16 {
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self> {
+        let line = Parser::new(input).line()?.into_str();
+        let bits = BitReader::from_hex(line)?;
+        Ok(Self { bits })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        let mut output = Extra::default();
+        decode(&mut self.bits.clone(), &mut output)?;
+        Ok(output.part1)
+    }
+
+    fn part2(&self) -> Result<u64> {
+        let mut output = Extra::default();
+        decode(&mut self.bits.clone(), &mut output)
+    }
+}
+
+enum Limit {
+    Count(usize),
+    End(usize),
+}
+
+impl Limit {
+    fn advance(&mut self, d: &BitReader<'_>) -> bool {
+        match self {
+            Limit::Count(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            Limit::End(end) if d.position() < *end => true,
+            _ => false,
+        }
+    }
+}
+
+fn decode(d: &mut BitReader<'_>, e: &mut Extra) -> Result<u64> {
+    let version = d.read(3)? as usize;
+    let id = d.read(3)?;
+
+    e.part1 += version;
+
+    if id == 4 {
+        let mut num = 0u64;
+
+        loop {
+            let more = d.read_bit()?;
+
+            num = num
+                .checked_mul(16)
+                .ok_or_else(|| anyhow!("literal overflow"))?;
+            num += d.read(4)? as u64;
+
+            if !more {
+                return Ok(num);
+            }
+        }
+    }
+
+    let mut l = if d.read_bit()? {
+        Limit::Count(d.read(11)? as usize)
+    } else {
+        let n = d.read(15)? as usize;
+        Limit::End(d.position() + n)
+    };
+
+    let (mut cur, op): (_, fn(_, _) -> _) = match id {
+        0 => (0, u64::checked_add),
+        1 => (1, u64::checked_mul),
+        2 => (u64::MAX, |a, b| Some(u64::min(a, b))),
+        3 => (u64::MIN, |a, b| Some(u64::max(a, b))),
+        id => {
+            let op = match id {
+                5 => |a, b| a > b,
+                6 => |a, b| a < b,
+                7 => |a, b| a == b,
+                id => bail!("unsupported packet type {}", id),
+            };
+
+            if !l.advance(d) {
+                bail!("missing first operand");
+            }
+
+            let a = decode(d, e)?;
+
+            if !l.advance(d) {
+                bail!("missing second operand");
+            }
+
+            let b = decode(d, e)?;
+
+            if l.advance(d) {
+                bail!("too many operands");
+            }
+
+            return Ok(if op(a, b) { 1 } else { 0 });
+        }
+    };
+
+    while l.advance(d) {
+        cur = op(cur, decode(d, e)?).ok_or_else(|| anyhow!("arithmetic overflow"))?;
+    }
+
+    Ok(cur)
+}
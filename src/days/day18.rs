@@ -0,0 +1,205 @@
+use anyhow::Result;
+
+use crate::{ParseError, Parseable, Parser, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Entry {
+    level: usize,
+    value: u32,
+}
+
+#[derive(Default, Clone)]
+struct Snail {
+    data: Vec<Entry>,
+}
+
+impl Snail {
+    fn add(&mut self, mut other: Self) {
+        if self.data.is_empty() {
+            self.data = other.data;
+        } else {
+            self.data.append(&mut other.data);
+
+            for e in &mut self.data {
+                e.level += 1;
+            }
+        }
+
+        process(&mut self.data);
+    }
+
+    /// Calculate the magnitude of a number of entries.
+    fn magnitude(&self) -> u32 {
+        let mut data = self.data.to_vec();
+
+        while data.len() != 1 {
+            let mut n = 0;
+
+            while n != data.len() && data.len() != 1 {
+                if data[n].level == data[n + 1].level {
+                    data[n].value = data[n].value * 3 + data[n + 1].value * 2;
+                    data[n].level -= 1;
+                    data.remove(n + 1);
+                    n = n.saturating_sub(1);
+                    continue;
+                }
+
+                n += 1;
+            }
+        }
+
+        assert_eq!(data[0].level, 0);
+        data[0].value
+    }
+}
+
+impl Parseable<'_> for Snail {
+    fn parse(p: &mut Parser<'_>) -> Result<Self, ParseError> {
+        let mut data = Vec::new();
+        parse_element(p, 0, &mut data)?;
+        Ok(Snail { data })
+    }
+}
+
+/// Parse a single element of a snailfish number, which is either a regular
+/// number or a pair.
+fn parse_element(
+    p: &mut Parser<'_>,
+    level: usize,
+    data: &mut Vec<Entry>,
+) -> Result<(), ParseError> {
+    if p.peek_char() == Some('[') {
+        p.expect("[")?;
+        parse_element(p, level + 1, data)?;
+        p.expect(",")?;
+        parse_element(p, level + 1, data)?;
+        p.expect("]")?;
+    } else {
+        let value = p.number()?;
+        data.push(Entry { level, value });
+    }
+
+    Ok(())
+}
+
+/// Snailfish numbers.
+pub struct Day18 {
+    all: Vec<Snail>,
+}
+
+impl Solution for Day18 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self> {
+        let all = crate::lines(input).collect::<Result<_, _>>()?;
+        Ok(Self { all })
+    }
+
+    fn part1(&self) -> Result<u32> {
+        let mut full = Snail::default();
+
+        for snail in &self.all {
+            full.add(snail.clone());
+        }
+
+        Ok(full.magnitude())
+    }
+
+    fn part2(&self) -> Result<u32> {
+        let mut part2 = u32::MIN;
+
+        for a in 0..self.all.len() {
+            for b in (a + 1)..self.all.len() {
+                {
+                    let mut a = self.all[a].clone();
+                    a.add(self.all[b].clone());
+                    part2 = u32::max(a.magnitude(), part2);
+                }
+
+                {
+                    let mut b = self.all[b].clone();
+                    b.add(self.all[a].clone());
+                    part2 = u32::max(b.magnitude(), part2);
+                }
+            }
+        }
+
+        Ok(part2)
+    }
+}
+
+fn process(num: &mut Vec<Entry>) {
+    let mut n = 0;
+
+    while n != num.len() {
+        // Special test here since length might be modified while exploding.
+        while n != num.len() {
+            explode(num, n);
+            n += 1;
+        }
+
+        for i in 0..num.len() {
+            if split(num, i) {
+                // Only need to re-process from the number that was split since
+                // the collection was only modified from this point.
+                n = i;
+                break;
+            }
+        }
+    }
+}
+
+fn explode(num: &mut Vec<Entry>, n: usize) {
+    let a = match num.get(n).copied() {
+        Some(e) if e.level > 4 => e,
+        _ => return,
+    };
+
+    let b = match num.get(n + 1).copied() {
+        Some(e) if e.level == a.level => e,
+        _ => return,
+    };
+
+    // NB: there is a before index.
+    if let Some(e) = n.checked_sub(1).and_then(|n| num.get_mut(n)) {
+        e.value += a.value;
+    }
+
+    if let Some(e) = num.get_mut(n) {
+        e.value = 0;
+        e.level -= 1;
+        // No input provides a number that is nested deeper than this.
+        assert_eq!(e.level, 4);
+    }
+
+    if let Some(next) = num.get_mut(n + 2) {
+        next.value += b.value;
+    }
+
+    num.remove(n + 1);
+}
+
+/// Split this number.
+fn split(num: &mut Vec<Entry>, n: usize) -> bool {
+    let e = match num.get_mut(n) {
+        Some(e) if e.value > 9 => e,
+        _ => return false,
+    };
+
+    let right = e.value.div_ceil(2);
+    let level = e.level + 1;
+
+    e.level = level;
+    e.value /= 2;
+
+    num.insert(
+        n + 1,
+        Entry {
+            level,
+            value: right,
+        },
+    );
+
+    true
+}
@@ -0,0 +1,167 @@
+//! Solutions for every day, and a registry which can be used to run them.
+
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+
+use crate::{Part, Solution};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+
+/// The answers produced by a single day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: String,
+    pub part2: String,
+    /// How long it took to produce the answers.
+    pub timings: Timings,
+}
+
+/// How long each step of solving a day took.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    /// The total time taken.
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+impl Answers {
+    /// Get the answer to the given part.
+    pub fn get(&self, part: Part) -> &str {
+        match part {
+            Part::One => &self.part1,
+            Part::Two => &self.part2,
+        }
+    }
+}
+
+/// A registered day.
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    /// The number of the day.
+    pub day: u32,
+    /// The name of the input file used by the day.
+    pub input: &'static str,
+    solve: fn(&str) -> Result<Answers>,
+    solve_part: fn(&str, Part) -> Result<String>,
+}
+
+impl Day {
+    /// Construct a registered day from a [Solution].
+    pub const fn new<T>(day: u32, input: &'static str) -> Self
+    where
+        T: Solution,
+    {
+        Self {
+            day,
+            input,
+            solve: solve::<T>,
+            solve_part: solve_part::<T>,
+        }
+    }
+
+    /// Solve the day for the given input.
+    pub fn solve(&self, input: &str) -> Result<Answers> {
+        (self.solve)(input)
+    }
+
+    /// Solve a single part of the day for the given input.
+    ///
+    /// This is useful for inputs which are only valid for one of the parts,
+    /// like some of the puzzle examples.
+    pub fn solve_part(&self, input: &str, part: Part) -> Result<String> {
+        (self.solve_part)(input, part)
+    }
+}
+
+/// All registered days.
+pub static DAYS: [Day; 18] = [
+    Day::new::<day01::Day01>(1, "01.txt"),
+    Day::new::<day02::Day02>(2, "02.txt"),
+    Day::new::<day03::Day03>(3, "03.txt"),
+    Day::new::<day04::Day04>(4, "04.txt"),
+    Day::new::<day05::Day05>(5, "05.txt"),
+    Day::new::<day06::Day06>(6, "06.txt"),
+    Day::new::<day07::Day07>(7, "07.txt"),
+    Day::new::<day08::Day08>(8, "08.txt"),
+    Day::new::<day09::Day09>(9, "09.txt"),
+    Day::new::<day10::Day10>(10, "10.txt"),
+    Day::new::<day11::Day11>(11, "11.txt"),
+    Day::new::<day12::Day12>(12, "12.txt"),
+    Day::new::<day13::Day13>(13, "13.txt"),
+    Day::new::<day14::Day14>(14, "14.txt"),
+    Day::new::<day15::Day15>(15, "15.txt"),
+    Day::new::<day16::Day16>(16, "16.txt"),
+    Day::new::<day17::Day17>(17, "17.txt"),
+    Day::new::<day18::Day18>(18, "18.txt"),
+];
+
+/// Get the registered day with the given number.
+pub fn get(day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+/// Parse and solve both parts of the given solution.
+fn solve<T>(input: &str) -> Result<Answers>
+where
+    T: Solution,
+{
+    let start = Instant::now();
+    let solution = T::parse(input)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let part1 = solution.part1()?;
+    let part1_time = start.elapsed();
+
+    let start = Instant::now();
+    let part2 = solution.part2()?;
+    let part2_time = start.elapsed();
+
+    Ok(Answers {
+        part1: part1.to_string(),
+        part2: part2.to_string(),
+        timings: Timings {
+            parse,
+            part1: part1_time,
+            part2: part2_time,
+        },
+    })
+}
+
+/// Parse and solve a single part of the given solution.
+fn solve_part<T>(input: &str, part: Part) -> Result<String>
+where
+    T: Solution,
+{
+    let solution = T::parse(input)?;
+
+    Ok(match part {
+        Part::One => solution.part1()?.to_string(),
+        Part::Two => solution.part2()?.to_string(),
+    })
+}
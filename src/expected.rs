@@ -0,0 +1,262 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+/// The name of the file storing expected answers, next to the input they
+/// belong to.
+pub const EXPECTED: &str = "answers.toml";
+
+/// An error raised when reading or writing expected answers.
+#[derive(Debug, Error)]
+pub enum ExpectedError {
+    #[error("failed to read `{}`", path.display())]
+    Read {
+        path: PathBuf,
+        #[source]
+        error: io::Error,
+    },
+    #[error("failed to write `{}`", path.display())]
+    Write {
+        path: PathBuf,
+        #[source]
+        error: io::Error,
+    },
+    #[error("{}:{line}: {message}", path.display())]
+    Syntax {
+        path: PathBuf,
+        line: usize,
+        message: &'static str,
+    },
+}
+
+/// A part of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Both parts, in order.
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// The key the part is stored under.
+    fn key(self) -> &'static str {
+        match self {
+            Part::One => "part1",
+            Part::Two => "part2",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "part1" => Some(Part::One),
+            "part2" => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => "part 1".fmt(f),
+            Part::Two => "part 2".fmt(f),
+        }
+    }
+}
+
+/// The outcome of checking an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status<'a> {
+    /// The answer matches the expected answer.
+    Correct,
+    /// The answer differs from the given expected answer.
+    Wrong(&'a str),
+    /// There is no expected answer.
+    Unknown,
+}
+
+/// Expected answers, keyed by input profile, day and part.
+///
+/// These are stored in a small subset of TOML, where each table is named
+/// after a profile and a day and holds a string for each part:
+///
+/// ```toml
+/// [default.01]
+/// part1 = "1709"
+/// part2 = "1761"
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Expected {
+    answers: BTreeMap<(String, u32), BTreeMap<Part, String>>,
+}
+
+impl Expected {
+    /// Construct an empty collection of answers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read answers from the given path.
+    pub fn read(path: &Path) -> Result<Self, ExpectedError> {
+        let string = fs::read_to_string(path).map_err(|error| ExpectedError::Read {
+            path: path.to_owned(),
+            error,
+        })?;
+
+        Self::parse(&string).map_err(|(line, message)| ExpectedError::Syntax {
+            path: path.to_owned(),
+            line,
+            message,
+        })
+    }
+
+    /// Write answers to the given path.
+    pub fn write(&self, path: &Path) -> Result<(), ExpectedError> {
+        fs::write(path, self.to_string()).map_err(|error| ExpectedError::Write {
+            path: path.to_owned(),
+            error,
+        })
+    }
+
+    /// Get the expected answer for the given profile, day and part.
+    pub fn get(&self, profile: &str, day: u32, part: Part) -> Option<&str> {
+        let parts = self.answers.get(&(profile.to_owned(), day))?;
+        Some(parts.get(&part)?.as_str())
+    }
+
+    /// Insert an expected answer.
+    pub fn insert(&mut self, profile: &str, day: u32, part: Part, answer: &str) {
+        self.answers
+            .entry((profile.to_owned(), day))
+            .or_default()
+            .insert(part, answer.to_owned());
+    }
+
+    /// Check the given answer against the expected one.
+    pub fn check(&self, profile: &str, day: u32, part: Part, answer: &str) -> Status<'_> {
+        match self.get(profile, day, part) {
+            Some(expected) if expected == answer => Status::Correct,
+            Some(expected) => Status::Wrong(expected),
+            None => Status::Unknown,
+        }
+    }
+
+    /// Parse answers, returning the line number and message on errors.
+    fn parse(input: &str) -> Result<Self, (usize, &'static str)> {
+        let mut expected = Self::new();
+        let mut current = None;
+
+        for (n, line) in input.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(table) = line.strip_prefix('[') {
+                let table = table.strip_suffix(']').ok_or((n + 1, "expected `]`"))?;
+
+                let (profile, day) = table
+                    .trim()
+                    .rsplit_once('.')
+                    .ok_or((n + 1, "expected table `[<profile>.<day>]`"))?;
+
+                let day = day.parse().map_err(|_| (n + 1, "bad day"))?;
+                current = Some((profile.to_owned(), day));
+                continue;
+            }
+
+            let (profile, day) = current
+                .as_ref()
+                .ok_or((n + 1, "expected table before answer"))?;
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or((n + 1, "expected `<part> = <answer>`"))?;
+
+            let part = Part::from_key(key.trim()).ok_or((n + 1, "expected `part1` or `part2`"))?;
+            let value = unquote(value.trim()).ok_or((n + 1, "bad answer"))?;
+            expected.insert(profile, *day, part, &value);
+        }
+
+        Ok(expected)
+    }
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+
+        for ((profile, day), parts) in &self.answers {
+            if !std::mem::take(&mut first) {
+                writeln!(f)?;
+            }
+
+            writeln!(f, "[{}.{:02}]", profile, day)?;
+
+            for (part, answer) in parts {
+                writeln!(f, "{} = {}", part.key(), Quoted(answer))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Unquote a value, which is either a basic string or a bare integer.
+fn unquote(value: &str) -> Option<String> {
+    let value = match value.strip_prefix('"') {
+        Some(value) => value.strip_suffix('"')?,
+        None if !value.is_empty() && value.chars().all(|c| c == '-' || c.is_ascii_digit()) => {
+            return Some(value.to_owned());
+        }
+        None => return None,
+    };
+
+    let mut out = String::new();
+    let mut it = value.chars();
+
+    while let Some(c) = it.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+
+        out.push(match it.next()? {
+            'n' => '\n',
+            't' => '\t',
+            '"' => '"',
+            '\\' => '\\',
+            _ => return None,
+        });
+    }
+
+    Some(out)
+}
+
+/// Helper to write a value as a basic string.
+struct Quoted<'a>(&'a str);
+
+impl fmt::Display for Quoted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"")?;
+
+        for c in self.0.chars() {
+            match c {
+                '\n' => write!(f, "\\n")?,
+                '\t' => write!(f, "\\t")?,
+                '"' => write!(f, "\\\"")?,
+                '\\' => write!(f, "\\\\")?,
+                c => write!(f, "{}", c)?,
+            }
+        }
+
+        write!(f, "\"")
+    }
+}
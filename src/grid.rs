@@ -0,0 +1,369 @@
+use std::ops::{Index, IndexMut};
+use std::slice;
+
+use crate::{ParseError, ParseErrorKind, Parseable, Parser};
+
+/// Offsets to the 4 orthogonal neighbours of a cell.
+const NEIGHBOURS4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets to the 8 neighbours of a cell, including diagonals.
+const NEIGHBOURS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A dense two-dimensional grid, stored row by row.
+///
+/// Cells are addressed by `(x, y)`, where `x` is the column and `y` the row.
+///
+/// A grid can be parsed from a character map, like the following, where each
+/// character is converted using [FromChar]. Parsing stops at the first blank
+/// line.
+///
+/// ```text
+/// 2199943210
+/// 3987894921
+/// 9856789892
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    data: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Construct a grid where every cell has the given value.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            data: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Construct a grid by calling the given function for every cell.
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let mut data = Vec::with_capacity(width * height);
+
+        for y in 0..height {
+            for x in 0..width {
+                data.push(f(x, y));
+            }
+        }
+
+        Self {
+            data,
+            width,
+            height,
+        }
+    }
+
+    /// Construct a grid from cells stored row by row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of cells is not a multiple of `width`.
+    pub fn from_vec(width: usize, data: Vec<T>) -> Self {
+        let height = data.len().checked_div(width).unwrap_or_default();
+
+        assert_eq!(
+            width * height,
+            data.len(),
+            "number of cells must be a multiple of the width"
+        );
+
+        Self {
+            data,
+            width,
+            height,
+        }
+    }
+
+    /// The number of columns in the grid.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of rows in the grid.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The number of cells in the grid.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Test if the grid has no cells.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Test if the given position is inside of the grid.
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Get the cell at the given position.
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if !self.contains(x, y) {
+            return None;
+        }
+
+        self.data.get(y * self.width + x)
+    }
+
+    /// Get the cell at the given position mutably.
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if !self.contains(x, y) {
+            return None;
+        }
+
+        self.data.get_mut(y * self.width + x)
+    }
+
+    /// Iterate over all positions in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Iterate over all cells together with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.data.iter())
+    }
+
+    /// Iterate mutably over all cells together with their positions, row by
+    /// row.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        self.positions().zip(self.data.iter_mut())
+    }
+
+    /// Iterate over all cells, row by row.
+    pub fn values(&self) -> slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Iterate mutably over all cells, row by row.
+    pub fn values_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    /// Iterate over the positions of the up to 4 orthogonal neighbours of the
+    /// given position which are inside of the grid.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.offsets(x, y, NEIGHBOURS4)
+    }
+
+    /// Iterate over the positions of the up to 8 neighbours, including
+    /// diagonals, of the given position which are inside of the grid.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.offsets(x, y, NEIGHBOURS8)
+    }
+
+    fn offsets<const N: usize>(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: [(isize, isize); N],
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+
+        offsets.into_iter().filter_map(move |(dx, dy)| {
+            let x = x.checked_add_signed(dx)?;
+            let y = y.checked_add_signed(dy)?;
+            (x < width && y < height).then_some((x, y))
+        })
+    }
+
+    /// Get the given row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row is out of bounds.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row out of bounds");
+        &self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Iterate over all rows.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    /// Iterate over the cells in the given column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column is out of bounds.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column out of bounds");
+        self.data.iter().skip(x).step_by(self.width)
+    }
+
+    /// Iterate over all columns.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// Construct a transposed copy of the grid, where rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |x, y| self[(y, x)].clone())
+    }
+
+    /// Construct a new grid by mapping every cell.
+    pub fn map<U, F>(&self, mut f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            data: self.data.iter().map(&mut f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Render the grid with one line per row, using the given function to
+    /// convert each cell into a character.
+    pub fn render<F>(&self, mut f: F) -> String
+    where
+        F: FnMut(&T) -> char,
+    {
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+
+        for row in self.rows() {
+            out.extend(row.iter().map(&mut f));
+            out.push('\n');
+        }
+
+        out
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        match self.get(x, y) {
+            Some(value) => value,
+            None => panic!("position ({}, {}) out of bounds", x, y),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        match self.get_mut(x, y) {
+            Some(value) => value,
+            None => panic!("position ({}, {}) out of bounds", x, y),
+        }
+    }
+}
+
+/// Conversion from a single character in a character map.
+pub trait FromChar: Sized {
+    /// Convert the character, or return `None` if it's not valid.
+    fn from_char(c: char) -> Option<Self>;
+}
+
+impl FromChar for char {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+}
+
+/// Converts `#` into `true` and `.` into `false`.
+impl FromChar for bool {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! from_char_digit {
+    ($ty:ty) => {
+        /// Converts a decimal digit.
+        impl FromChar for $ty {
+            fn from_char(c: char) -> Option<Self> {
+                Some(c.to_digit(10)? as $ty)
+            }
+        }
+    };
+}
+
+from_char_digit!(u8);
+from_char_digit!(u16);
+from_char_digit!(u32);
+from_char_digit!(u64);
+from_char_digit!(usize);
+
+impl<T> Parseable<'_> for Grid<T>
+where
+    T: FromChar,
+{
+    fn parse(p: &mut Parser<'_>) -> Result<Self, ParseError> {
+        let mut data = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        while let Some(mut row) = p.next_line() {
+            let line = row.as_str();
+
+            if line.is_empty() {
+                break;
+            }
+
+            let start = data.len();
+
+            while let Some(c) = row.peek_char() {
+                match T::from_char(c) {
+                    Some(value) => data.push(value),
+                    None => {
+                        let kind = ParseErrorKind::Expected("a grid cell".to_owned());
+                        return Err(row.error_at(row.peek(1), kind));
+                    }
+                }
+
+                row.next_char();
+            }
+
+            let len = data.len() - start;
+
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    let kind = ParseErrorKind::Expected(format!("a row of width {}", width));
+                    return Err(row.error_at(line, kind));
+                }
+                _ => {}
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            data,
+            width: width.unwrap_or_default(),
+            height,
+        })
+    }
+}
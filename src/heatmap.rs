@@ -0,0 +1,109 @@
+//! Rendering of counts on a grid as images and text, where the intensity of a
+//! cell depends on its count.
+//!
+//! Large maps are expected to be downsampled before they're rendered, like
+//! [count_map] does.
+//!
+//! [count_map]: crate::segments::count_map
+
+use std::fmt::Write;
+
+use crate::Grid;
+
+/// Counts on a grid which can be rendered as a heatmap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heatmap {
+    grid: Grid<u64>,
+    max: u64,
+}
+
+impl Heatmap {
+    /// Construct a heatmap of the given counts.
+    pub fn new(grid: Grid<u64>) -> Self {
+        let max = grid.values().copied().max().unwrap_or_default();
+        Self { grid, max }
+    }
+
+    /// Access the counts.
+    pub fn grid(&self) -> &Grid<u64> {
+        &self.grid
+    }
+
+    /// The highest count.
+    pub fn max(&self) -> u64 {
+        self.max
+    }
+
+    /// Encode as a binary greyscale PGM image, where a count of zero is black
+    /// and the highest count is white.
+    pub fn to_pgm(&self) -> Vec<u8> {
+        let mut out = self.header("P5");
+
+        out.extend(self.grid.values().map(|&n| {
+            if self.max == 0 {
+                0
+            } else {
+                (u128::from(n) * 255 / u128::from(self.max)) as u8
+            }
+        }));
+
+        out
+    }
+
+    /// Encode as a binary colour PPM image, where a count of zero is black
+    /// and higher counts go from blue through yellow to red.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut out = self.header("P6");
+
+        for &n in self.grid.values() {
+            out.extend(self.color(n));
+        }
+
+        out
+    }
+
+    /// Render as text, with one character for each cell.
+    ///
+    /// Cells with a count of zero are rendered as `.`, counts up to 9 as
+    /// digits and higher counts as `+`.
+    pub fn to_ascii(&self) -> String {
+        self.grid.render(|&n| match n {
+            0 => '.',
+            1..=9 => char::from_digit(n as u32, 10).unwrap_or('+'),
+            _ => '+',
+        })
+    }
+
+    fn header(&self, magic: &str) -> Vec<u8> {
+        let mut header = String::new();
+        let _ = write!(
+            header,
+            "{}\n{} {}\n255\n",
+            magic,
+            self.grid.width(),
+            self.grid.height()
+        );
+        header.into_bytes()
+    }
+
+    /// The colour of the given count as RGB.
+    fn color(&self, n: u64) -> [u8; 3] {
+        if n == 0 {
+            return [0, 0, 0];
+        }
+
+        // How far along the ramp the count is, from 0 to 510.
+        let t = if self.max > 1 {
+            (u128::from(n - 1) * 510 / u128::from(self.max - 1)) as u64
+        } else {
+            0
+        };
+
+        if t <= 255 {
+            let t = t as u8;
+            [t, t, 255 - t]
+        } else {
+            [255, (510 - t) as u8, 0]
+        }
+    }
+}
@@ -0,0 +1,320 @@
+use std::collections::BTreeSet;
+use std::env;
+use std::ffi::OsString;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+/// Environment variable which can be used to point to a directory of inputs.
+pub const INPUT_DIR: &str = "AOC_INPUT_DIR";
+
+/// The profile used for the input that is stored directly in the input
+/// directory, as opposed to in a profile directory.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// An error raised while loading input.
+#[derive(Debug, Error)]
+pub enum LoadError {
+    #[error("failed to read `{}`", path.display())]
+    Read {
+        path: PathBuf,
+        #[source]
+        error: io::Error,
+    },
+    #[error("failed to read stdin")]
+    Stdin(#[source] io::Error),
+    #[error("input is not valid utf-8")]
+    Utf8(
+        #[source]
+        #[from]
+        std::string::FromUtf8Error,
+    ),
+    #[error("could not find input `{name}`, tried: {}", Tried(tried))]
+    NotFound { name: String, tried: Vec<PathBuf> },
+}
+
+/// Where a [Loader] reads its input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// Read input from stdin.
+    Stdin,
+    /// Read input from an explicit path.
+    Path(PathBuf),
+    /// Search the input directories for the named input.
+    Search,
+}
+
+impl Source {
+    /// Construct a source from a command line argument, where `-` means stdin.
+    pub fn from_arg(arg: impl Into<OsString>) -> Self {
+        let arg = arg.into();
+
+        if arg == "-" {
+            Self::Stdin
+        } else {
+            Self::Path(PathBuf::from(arg))
+        }
+    }
+}
+
+/// Loads puzzle input.
+///
+/// Input is looked up in the following order:
+/// * An explicit path or stdin, as configured through [Loader::with_source].
+/// * The directory specified by the `AOC_INPUT_DIR` environment variable.
+/// * The `input` directory of the crate.
+///
+/// Each input directory can hold inputs for several profiles, where the
+/// inputs of the profile `<profile>` are stored in `<dir>/<profile>/NN.txt`.
+/// The inputs stored directly in the input directory belong to the
+/// [DEFAULT_PROFILE].
+#[derive(Debug, Clone)]
+pub struct Loader {
+    source: Source,
+    profile: Option<String>,
+    dirs: Vec<PathBuf>,
+}
+
+impl Loader {
+    /// Construct a loader which searches the default input directories.
+    pub fn new() -> Self {
+        let mut dirs = Vec::new();
+
+        if let Some(dir) = env::var_os(INPUT_DIR) {
+            dirs.push(PathBuf::from(dir));
+        }
+
+        if let Some(dir) = env::var_os("CARGO_MANIFEST_DIR") {
+            dirs.push(PathBuf::from(dir).join("input"));
+        }
+
+        let built = Path::new(env!("CARGO_MANIFEST_DIR")).join("input");
+
+        if !dirs.contains(&built) {
+            dirs.push(built);
+        }
+
+        Self {
+            source: Source::Search,
+            profile: None,
+            dirs,
+        }
+    }
+
+    /// Construct a loader which uses the first command line argument as the
+    /// source of input if present.
+    pub fn from_args() -> Self {
+        let mut loader = Self::new();
+
+        if let Some(arg) = env::args_os().nth(1) {
+            loader.source = Source::from_arg(arg);
+        }
+
+        loader
+    }
+
+    /// Set the source to read input from.
+    pub fn with_source(mut self, source: Source) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Get the configured source of input.
+    pub fn source(&self) -> &Source {
+        &self.source
+    }
+
+    /// Set the profile to load input for.
+    pub fn with_profile(mut self, profile: &str) -> Self {
+        self.profile = if profile == DEFAULT_PROFILE {
+            None
+        } else {
+            Some(profile.to_owned())
+        };
+
+        self
+    }
+
+    /// Get the profile that input is loaded for.
+    ///
+    /// This is `None` if input is read from an explicit source, since it's not
+    /// known who the input belongs to.
+    pub fn profile(&self) -> Option<&str> {
+        match self.source {
+            Source::Search => Some(self.profile.as_deref().unwrap_or(DEFAULT_PROFILE)),
+            _ => None,
+        }
+    }
+
+    /// List all profiles which have input available, starting with the
+    /// [DEFAULT_PROFILE] if there is input for it.
+    pub fn profiles(&self) -> Result<Vec<String>, LoadError> {
+        let mut default = false;
+        let mut profiles = BTreeSet::new();
+
+        for dir in &self.dirs {
+            let entries = match fs::read_dir(dir) {
+                Ok(entries) => entries,
+                Err(error) if error.kind() == io::ErrorKind::NotFound => continue,
+                Err(error) => {
+                    return Err(LoadError::Read {
+                        path: dir.clone(),
+                        error,
+                    })
+                }
+            };
+
+            for entry in entries {
+                let entry = entry.map_err(|error| LoadError::Read {
+                    path: dir.clone(),
+                    error,
+                })?;
+
+                let path = entry.path();
+
+                if path.is_dir() {
+                    if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+                        if has_input(&path) {
+                            profiles.insert(name.to_owned());
+                        }
+                    }
+                } else if is_input(&path) {
+                    default = true;
+                }
+            }
+        }
+
+        let mut out = Vec::new();
+
+        if default {
+            out.push(DEFAULT_PROFILE.to_owned());
+        }
+
+        out.extend(profiles);
+        Ok(out)
+    }
+
+    /// Get the directories which are searched for input.
+    pub fn dirs(&self) -> &[PathBuf] {
+        &self.dirs
+    }
+
+    /// Find a file with the given name in the input directories.
+    ///
+    /// This ignores any explicitly configured source or profile.
+    pub fn find(&self, name: &str) -> Result<PathBuf, LoadError> {
+        Ok(self.search(Path::new(name))?.1)
+    }
+
+    /// Find the input with the given name for the current profile.
+    pub fn find_input(&self, name: &str) -> Result<PathBuf, LoadError> {
+        Ok(self.search(&self.relative(name))?.1)
+    }
+
+    /// Find the input directory which holds the input with the given name for
+    /// the current profile.
+    pub fn find_dir(&self, name: &str) -> Result<&Path, LoadError> {
+        Ok(self.search(&self.relative(name))?.0)
+    }
+
+    /// The path of the named input of the current profile, relative to an
+    /// input directory.
+    fn relative(&self, name: &str) -> PathBuf {
+        match &self.profile {
+            Some(profile) => Path::new(profile).join(name),
+            None => PathBuf::from(name),
+        }
+    }
+
+    fn search(&self, name: &Path) -> Result<(&Path, PathBuf), LoadError> {
+        let mut tried = Vec::new();
+
+        for dir in &self.dirs {
+            let path = dir.join(name);
+
+            if path.is_file() {
+                return Ok((dir, path));
+            }
+
+            tried.push(path);
+        }
+
+        Err(LoadError::NotFound {
+            name: name.display().to_string(),
+            tried,
+        })
+    }
+
+    /// Load the input with the given name.
+    pub fn load(&self, name: &str) -> Result<String, LoadError> {
+        let bytes = match &self.source {
+            Source::Stdin => {
+                let mut bytes = Vec::new();
+                io::stdin()
+                    .read_to_end(&mut bytes)
+                    .map_err(LoadError::Stdin)?;
+                bytes
+            }
+            Source::Path(path) => read(path)?,
+            Source::Search => read(&self.find_input(name)?)?,
+        };
+
+        Ok(String::from_utf8(bytes)?)
+    }
+}
+
+impl Default for Loader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn read(path: &Path) -> Result<Vec<u8>, LoadError> {
+    fs::read(path).map_err(|error| LoadError::Read {
+        path: path.to_owned(),
+        error,
+    })
+}
+
+/// Test if the given directory contains any inputs.
+fn has_input(dir: &Path) -> bool {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(..) => return false,
+    };
+
+    entries
+        .flatten()
+        .any(|e| e.path().is_file() && is_input(&e.path()))
+}
+
+/// Test if the given path looks like a day's input, like `01.txt`.
+fn is_input(path: &Path) -> bool {
+    let stem = match path.file_stem().and_then(|s| s.to_str()) {
+        Some(stem) => stem,
+        None => return false,
+    };
+
+    path.extension().is_some_and(|e| e == "txt") && stem.chars().all(|c| c.is_ascii_digit())
+}
+
+struct Tried<'a>(&'a [PathBuf]);
+
+impl fmt::Display for Tried<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.0.iter();
+
+        if let Some(path) = it.next() {
+            write!(f, "`{}`", path.display())?;
+        }
+
+        for path in it {
+            write!(f, ", `{}`", path.display())?;
+        }
+
+        Ok(())
+    }
+}
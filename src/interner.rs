@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::fmt;
+
+/// A symbol for a string stored in an [Interner].
+///
+/// Symbols are allocated sequentially from zero, so they can be used as
+/// indexes into dense collections through [Symbol::index].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    /// Get the index of the symbol.
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Interns strings, mapping each distinct string to a [Symbol] and back.
+#[derive(Debug, Default, Clone)]
+pub struct Interner {
+    symbols: HashMap<Box<str>, Symbol>,
+    strings: Vec<Box<str>>,
+}
+
+impl Interner {
+    /// Construct a new empty interner.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Intern the given string, returning its symbol.
+    pub fn intern(&mut self, string: &str) -> Symbol {
+        if let Some(symbol) = self.symbols.get(string) {
+            return *symbol;
+        }
+
+        let symbol = match u32::try_from(self.strings.len()) {
+            Ok(id) => Symbol(id),
+            Err(..) => panic!("too many interned strings"),
+        };
+
+        self.strings.push(string.into());
+        self.symbols.insert(string.into(), symbol);
+        symbol
+    }
+
+    /// Get the symbol of the given string if it has been interned.
+    pub fn get(&self, string: &str) -> Option<Symbol> {
+        self.symbols.get(string).copied()
+    }
+
+    /// Get the string of the given symbol.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the symbol was not allocated by this interner.
+    pub fn resolve(&self, symbol: Symbol) -> &str {
+        &self.strings[symbol.index()]
+    }
+
+    /// The number of interned strings.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Test if no strings have been interned.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Iterate over all symbols and their strings in allocation order.
+    pub fn iter(&self) -> impl Iterator<Item = (Symbol, &str)> {
+        self.strings
+            .iter()
+            .enumerate()
+            .map(|(n, s)| (Symbol(n as u32), &**s))
+    }
+}
@@ -0,0 +1,174 @@
+//! A model of a growing school of lanternfish.
+//!
+//! Every fish has a timer which counts down by one each day. A fish whose
+//! timer is at zero instead resets it to the spawn timer and produces a new
+//! fish with the newborn timer. The school is tracked as the number of fish
+//! with each timer, so the simulation doesn't depend on how many fish there
+//! are.
+
+use thiserror::Error;
+
+use crate::matrix::{Arithmetic, Matrix};
+
+/// An error raised by the lanternfish model.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum LanternfishError {
+    #[error("spawn timer {spawn} is larger than the newborn timer {newborn}")]
+    Timers { spawn: usize, newborn: usize },
+    #[error("timer {timer} is larger than the newborn timer {newborn}")]
+    Timer { timer: usize, newborn: usize },
+    #[error("school counts {len} timers, expected {states}")]
+    School { len: usize, states: usize },
+    #[error("count overflows")]
+    Overflow,
+}
+
+/// The timers which govern how lanternfish reproduce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Model {
+    spawn: usize,
+    newborn: usize,
+}
+
+impl Model {
+    /// Construct a model where the timer of a fish resets to `spawn` after
+    /// spawning, and newborn fish start at `newborn`.
+    pub fn new(spawn: usize, newborn: usize) -> Result<Self, LanternfishError> {
+        if spawn > newborn {
+            return Err(LanternfishError::Timers { spawn, newborn });
+        }
+
+        Ok(Self { spawn, newborn })
+    }
+
+    /// The number of distinct timer values.
+    pub fn states(&self) -> usize {
+        self.newborn + 1
+    }
+
+    /// Count the number of fish with each timer.
+    pub fn school<I>(&self, timers: I) -> Result<Vec<u64>, LanternfishError>
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let mut school = vec![0; self.states()];
+
+        for timer in timers {
+            match school.get_mut(timer) {
+                Some(count) => *count += 1,
+                None => {
+                    return Err(LanternfishError::Timer {
+                        timer,
+                        newborn: self.newborn,
+                    })
+                }
+            }
+        }
+
+        Ok(school)
+    }
+
+    /// The matrix which advances the number of fish with each timer by one
+    /// day.
+    pub fn transition<A>(&self, arithmetic: &A) -> Matrix<A::Value>
+    where
+        A: Arithmetic,
+    {
+        Matrix::from_fn(self.states(), |row, column| {
+            // Timers count down, except for zero which wraps around to the
+            // newborn and the spawn timers.
+            let mut n = u64::from(row + 1 == column);
+
+            if column == 0 {
+                n += u64::from(row == self.newborn) + u64::from(row == self.spawn);
+            }
+
+            arithmetic.value(n)
+        })
+    }
+
+    /// Count the fish after the given number of days, starting from a school
+    /// as produced by [Model::school].
+    ///
+    /// This takes `O(log days)` matrix multiplications. Fails if the school
+    /// doesn't have a count for every timer.
+    pub fn count<A>(
+        &self,
+        school: &[u64],
+        days: u64,
+        arithmetic: &A,
+    ) -> Result<A::Value, LanternfishError>
+    where
+        A: Arithmetic,
+    {
+        self.check(school)?;
+
+        let school = school
+            .iter()
+            .map(|n| arithmetic.value(*n))
+            .collect::<Vec<_>>();
+
+        let counts = self
+            .transition(arithmetic)
+            .pow(days, arithmetic)
+            .and_then(|m| m.mul_vec(&school, arithmetic))
+            .ok_or(LanternfishError::Overflow)?;
+
+        let mut total = arithmetic.zero();
+
+        for count in &counts {
+            total = arithmetic
+                .add(&total, count)
+                .ok_or(LanternfishError::Overflow)?;
+        }
+
+        Ok(total)
+    }
+
+    /// Count the fish after the given number of days, one day at a time.
+    ///
+    /// This takes `O(days)` time, and is the reference implementation for
+    /// [Model::count]. Fails if the school doesn't have a count for every
+    /// timer.
+    pub fn simulate(&self, school: &[u64], days: u64) -> Result<u64, LanternfishError> {
+        self.check(school)?;
+
+        let mut school = school.to_vec();
+
+        for _ in 0..days {
+            school.rotate_left(1);
+            let spawned = school[self.newborn];
+            school[self.spawn] = school[self.spawn]
+                .checked_add(spawned)
+                .ok_or(LanternfishError::Overflow)?;
+        }
+
+        school
+            .into_iter()
+            .try_fold(0u64, |a, b| a.checked_add(b))
+            .ok_or(LanternfishError::Overflow)
+    }
+
+    /// Check that the school has a count for every timer.
+    fn check(&self, school: &[u64]) -> Result<(), LanternfishError> {
+        if school.len() != self.states() {
+            return Err(LanternfishError::School {
+                len: school.len(),
+                states: self.states(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for Model {
+    /// The model from the puzzle, where fish spawn every 7 days and newborn
+    /// fish take an extra 2 days.
+    fn default() -> Self {
+        Self {
+            spawn: 6,
+            newborn: 8,
+        }
+    }
+}
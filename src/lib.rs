@@ -1,31 +1,85 @@
-use std::env;
-use std::fs;
-use std::path::PathBuf;
+// Allows the derives to refer to `::aoc` from inside of this crate.
+extern crate self as aoc;
 
-use anyhow::{anyhow, Result};
+use std::io::BufRead;
+
+use anyhow::Result;
+
+pub use aoc_derive::{parse_pattern, Parseable};
+
+pub mod bench;
+pub mod bingo;
+pub mod bits;
+pub mod days;
+pub mod heatmap;
+
+mod bitvec;
+pub use self::bitvec::{BitVec, ColumnCounts};
+
+mod expected;
+pub use self::expected::{Expected, ExpectedError, Part, Status, EXPECTED};
+
+mod grid;
+pub use self::grid::{FromChar, Grid};
+
+mod input;
+pub use self::input::{LoadError, Loader, Source, DEFAULT_PROFILE, INPUT_DIR};
+
+mod interner;
+pub use self::interner::{Interner, Symbol};
+
+pub mod lanternfish;
+
+mod lines;
+pub use self::lines::{Lines, ReadLines};
 
 mod parse;
-pub use self::parse::{parse, ParseError, Parseable, Parser, Skip};
+pub use self::parse::{
+    parse, Delimited, Location, ParseError, ParseErrorKind, Parseable, Parser, Sections, Skip,
+};
 
+mod pattern;
+pub use self::pattern::Pattern;
+
+pub mod matrix;
+pub mod ocr;
+pub mod optimize;
+pub mod runner;
+pub mod search;
+pub mod segments;
+
+mod solution;
+pub use self::solution::Solution;
+
+/// Load the input with the given name.
+///
+/// The first command line argument is used as the path to the input if
+/// present, where `-` reads the input from stdin. See [Loader] for the full
+/// lookup order.
 pub fn load(name: &str) -> Result<String> {
-    let dir =
-        env::var_os("CARGO_MANIFEST_DIR").ok_or_else(|| anyhow!("missing CARGO_MANIFEST_DIR"))?;
-    let p = PathBuf::from(&dir).join("input").join(name);
-    let string = String::from_utf8(fs::read(&p)?)?;
-    Ok(string)
+    Ok(Loader::from_args().load(name)?)
 }
 
-/// Convert the given bufreader into input lines using the given [Parseable] as
+/// Lazily parse each line of the given input using the given [Parseable] as
 /// a template.
-pub fn lines<T>(buf: String) -> Result<Vec<T>>
+///
+/// Parsed values may borrow from the input.
+pub fn lines<'a, T>(input: &'a str) -> Lines<'a, T>
 where
-    T: for<'de> Parseable<'de>,
+    T: 'a + Parseable<'a>,
 {
-    let mut out = Vec::new();
-
-    for line in buf.lines() {
-        out.push(parse(line)?);
-    }
+    Lines::new(input)
+}
 
-    Ok(out)
+/// Lazily read and parse each line from the given reader using the given
+/// [Parseable] as a template.
+///
+/// Only one line is kept in memory at a time, which makes this suitable for
+/// large inputs.
+pub fn read_lines<R, T>(reader: R) -> ReadLines<R, T>
+where
+    R: BufRead,
+    T: for<'de> Parseable<'de>,
+{
+    ReadLines::new(reader)
 }
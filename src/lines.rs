@@ -0,0 +1,80 @@
+use std::io::BufRead;
+use std::marker;
+use std::str;
+
+use crate::{ParseError, Parseable, Parser};
+
+/// An iterator over parsed lines borrowed from a string.
+///
+/// See [lines][crate::lines()].
+pub struct Lines<'a, T> {
+    input: &'a str,
+    lines: str::Lines<'a>,
+    _marker: marker::PhantomData<T>,
+}
+
+impl<'a, T> Lines<'a, T> {
+    pub(crate) fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            lines: input.lines(),
+            _marker: marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Lines<'a, T>
+where
+    T: 'a + Parseable<'a>,
+{
+    type Item = Result<T, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = self.lines.next()?;
+        Some(Parser::with_source(self.input, line, 0).parse())
+    }
+}
+
+/// An iterator over parsed lines read from a [BufRead].
+///
+/// See [read_lines][crate::read_lines()].
+pub struct ReadLines<R, T> {
+    reader: R,
+    buf: String,
+    line: usize,
+    _marker: marker::PhantomData<T>,
+}
+
+impl<R, T> ReadLines<R, T> {
+    pub(crate) fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buf: String::new(),
+            line: 0,
+            _marker: marker::PhantomData,
+        }
+    }
+}
+
+impl<R, T> Iterator for ReadLines<R, T>
+where
+    R: BufRead,
+    T: for<'de> Parseable<'de>,
+{
+    type Item = Result<T, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.buf.clear();
+
+        match self.reader.read_line(&mut self.buf) {
+            Ok(0) => return None,
+            Ok(_) => (),
+            Err(e) => return Some(Err(e.into())),
+        }
+
+        let line = self.buf.trim_end_matches(['\n', '\r']);
+        let mut p = Parser::with_source(line, line, self.line);
+        self.line += 1;
+        Some(p.parse())
+    }
+}
@@ -0,0 +1,238 @@
+//! Square matrices over a configurable [Arithmetic], with exponentiation by
+//! squaring.
+//!
+//! Arithmetic is fallible so that fixed width counts can report overflow
+//! instead of silently wrapping around.
+
+use std::fmt;
+
+/// Arithmetic over the values stored in a [Matrix].
+pub trait Arithmetic {
+    /// The type of values.
+    type Value: Clone + fmt::Debug;
+
+    /// The additive identity.
+    fn zero(&self) -> Self::Value;
+
+    /// Convert a number into a value.
+    fn value(&self, n: u64) -> Self::Value;
+
+    /// Add two values, or return `None` if the result can't be represented.
+    fn add(&self, a: &Self::Value, b: &Self::Value) -> Option<Self::Value>;
+
+    /// Multiply two values, or return `None` if the result can't be
+    /// represented.
+    fn mul(&self, a: &Self::Value, b: &Self::Value) -> Option<Self::Value>;
+}
+
+/// Arithmetic over `u64` which fails on overflow.
+#[derive(Debug, Clone, Copy)]
+pub struct Checked;
+
+impl Arithmetic for Checked {
+    type Value = u64;
+
+    fn zero(&self) -> u64 {
+        0
+    }
+
+    fn value(&self, n: u64) -> u64 {
+        n
+    }
+
+    fn add(&self, a: &u64, b: &u64) -> Option<u64> {
+        a.checked_add(*b)
+    }
+
+    fn mul(&self, a: &u64, b: &u64) -> Option<u64> {
+        a.checked_mul(*b)
+    }
+}
+
+/// Arithmetic over `u64` modulo the given non-zero number.
+#[derive(Debug, Clone, Copy)]
+pub struct Modulo(pub u64);
+
+impl Arithmetic for Modulo {
+    type Value = u64;
+
+    fn zero(&self) -> u64 {
+        0
+    }
+
+    fn value(&self, n: u64) -> u64 {
+        n % self.0
+    }
+
+    fn add(&self, a: &u64, b: &u64) -> Option<u64> {
+        Some(((u128::from(*a) + u128::from(*b)) % u128::from(self.0)) as u64)
+    }
+
+    fn mul(&self, a: &u64, b: &u64) -> Option<u64> {
+        Some((u128::from(*a) * u128::from(*b) % u128::from(self.0)) as u64)
+    }
+}
+
+/// Exact arithmetic over arbitrary precision numbers.
+#[cfg(feature = "bigint")]
+#[derive(Debug, Clone, Copy)]
+pub struct Exact;
+
+#[cfg(feature = "bigint")]
+impl Arithmetic for Exact {
+    type Value = num_bigint::BigUint;
+
+    fn zero(&self) -> Self::Value {
+        Self::Value::default()
+    }
+
+    fn value(&self, n: u64) -> Self::Value {
+        Self::Value::from(n)
+    }
+
+    fn add(&self, a: &Self::Value, b: &Self::Value) -> Option<Self::Value> {
+        Some(a + b)
+    }
+
+    fn mul(&self, a: &Self::Value, b: &Self::Value) -> Option<Self::Value> {
+        Some(a * b)
+    }
+}
+
+/// A square matrix, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix<T> {
+    size: usize,
+    data: Vec<T>,
+}
+
+impl<T> Matrix<T>
+where
+    T: Clone + fmt::Debug,
+{
+    /// Construct a matrix by calling the given function with the row and
+    /// column of every cell.
+    pub fn from_fn<F>(size: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let mut data = Vec::with_capacity(size * size);
+
+        for row in 0..size {
+            for column in 0..size {
+                data.push(f(row, column));
+            }
+        }
+
+        Self { size, data }
+    }
+
+    /// Construct the identity matrix of the given size.
+    pub fn identity<A>(arithmetic: &A, size: usize) -> Self
+    where
+        A: Arithmetic<Value = T>,
+    {
+        Self::from_fn(size, |row, column| {
+            arithmetic.value(u64::from(row == column))
+        })
+    }
+
+    /// The number of rows and columns.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Get the value at the given row and column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is out of bounds.
+    pub fn get(&self, row: usize, column: usize) -> &T {
+        assert!(row < self.size && column < self.size, "out of bounds");
+        &self.data[row * self.size + column]
+    }
+
+    /// Multiply with another matrix of the same size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sizes differ.
+    pub fn mul<A>(&self, other: &Self, arithmetic: &A) -> Option<Self>
+    where
+        A: Arithmetic<Value = T>,
+    {
+        assert_eq!(self.size, other.size, "matrix sizes must match");
+
+        let mut data = Vec::with_capacity(self.data.len());
+
+        for row in 0..self.size {
+            for column in 0..self.size {
+                let mut sum = arithmetic.zero();
+
+                for n in 0..self.size {
+                    let product = arithmetic.mul(self.get(row, n), other.get(n, column))?;
+                    sum = arithmetic.add(&sum, &product)?;
+                }
+
+                data.push(sum);
+            }
+        }
+
+        Some(Self {
+            size: self.size,
+            data,
+        })
+    }
+
+    /// Multiply with a column vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the vector differs from the size.
+    pub fn mul_vec<A>(&self, vector: &[T], arithmetic: &A) -> Option<Vec<T>>
+    where
+        A: Arithmetic<Value = T>,
+    {
+        assert_eq!(self.size, vector.len(), "vector length must match");
+
+        let mut out = Vec::with_capacity(self.size);
+
+        for row in 0..self.size {
+            let mut sum = arithmetic.zero();
+
+            for (n, value) in vector.iter().enumerate() {
+                let product = arithmetic.mul(self.get(row, n), value)?;
+                sum = arithmetic.add(&sum, &product)?;
+            }
+
+            out.push(sum);
+        }
+
+        Some(out)
+    }
+
+    /// Raise the matrix to the given power through repeated squaring, which
+    /// takes `O(log exp)` multiplications.
+    pub fn pow<A>(&self, mut exp: u64, arithmetic: &A) -> Option<Self>
+    where
+        A: Arithmetic<Value = T>,
+    {
+        let mut out = Self::identity(arithmetic, self.size);
+        let mut base = self.clone();
+
+        while exp > 0 {
+            if exp % 2 == 1 {
+                out = out.mul(&base, arithmetic)?;
+            }
+
+            exp /= 2;
+
+            // NB: squaring once more than needed could overflow.
+            if exp > 0 {
+                base = base.mul(&base, arithmetic)?;
+            }
+        }
+
+        Some(out)
+    }
+}
@@ -0,0 +1,361 @@
+//! Recognition of the block letters which some puzzles draw as their answer.
+//!
+//! Two fonts are supported: the small one which is 4 cells wide and 6 cells
+//! tall, and the large one which is 6 cells wide and 10 cells tall. Letters
+//! are drawn side by side with a fixed spacing, like this:
+//!
+//! ```text
+//! ###  ###   ##  #  #
+//! #  # #  # #  # # #
+//! #  # #  # #    ##
+//! ###  ###  #    # #
+//! # #  #    #  # # #
+//! #  # #     ##  #  #
+//! ```
+
+use thiserror::Error;
+
+use crate::Grid;
+
+/// An error raised when recognising letters.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    #[error("nothing is drawn")]
+    Empty,
+    #[error("no font is {0} cells tall")]
+    UnsupportedHeight(usize),
+    #[error("unrecognised glyph #{index} at column {column}:\n{glyph}")]
+    UnknownGlyph {
+        /// The index of the glyph, counting from zero.
+        index: usize,
+        /// The column in the grid at which the glyph starts.
+        column: usize,
+        /// The cells of the glyph, rendered with `#` and `.`.
+        glyph: String,
+    },
+}
+
+/// A font of block letters.
+#[derive(Debug)]
+pub struct Font {
+    width: usize,
+    height: usize,
+    /// The distance between the starts of two adjacent letters.
+    spacing: usize,
+    glyphs: &'static [(char, &'static [&'static str])],
+}
+
+impl Font {
+    /// The small font, where each letter is 4 cells wide and 6 cells tall.
+    pub const SMALL: Font = Font {
+        width: 4,
+        height: 6,
+        spacing: 5,
+        glyphs: SMALL_GLYPHS,
+    };
+
+    /// The large font, where each letter is 6 cells wide and 10 cells tall.
+    pub const LARGE: Font = Font {
+        width: 6,
+        height: 10,
+        spacing: 8,
+        glyphs: LARGE_GLYPHS,
+    };
+
+    /// The font with the given height.
+    pub fn with_height(height: usize) -> Option<&'static Font> {
+        [&Font::SMALL, &Font::LARGE]
+            .into_iter()
+            .find(|font| font.height == height)
+    }
+
+    /// The height of a letter.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The width of a letter.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Recognise the letters in the given grid.
+    ///
+    /// Blank rows above and below the letters and blank columns to the left
+    /// of them are ignored. Letters with a blank first column, like `I`, may
+    /// still start the line.
+    pub fn recognize(&self, grid: &Grid<bool>) -> Result<String, OcrError> {
+        let bounds = Bounds::of(grid).ok_or(OcrError::Empty)?;
+
+        if bounds.height != self.height {
+            return Err(OcrError::UnsupportedHeight(bounds.height));
+        }
+
+        self.recognize_in(grid, &bounds)
+    }
+
+    /// Draw the given text, or return `None` if a letter is not part of the
+    /// font.
+    pub fn render(&self, text: &str) -> Option<Grid<bool>> {
+        let glyphs = text
+            .chars()
+            .map(|c| {
+                self.glyphs
+                    .iter()
+                    .find(|(g, _)| *g == c)
+                    .map(|(_, rows)| *rows)
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let width = (glyphs.len() * self.spacing).saturating_sub(self.spacing - self.width);
+
+        Some(Grid::from_fn(width, self.height, |x, y| {
+            let (n, x) = (x / self.spacing, x % self.spacing);
+            x < self.width && glyphs[n][y].as_bytes()[x] == b'#'
+        }))
+    }
+
+    fn recognize_in(&self, grid: &Grid<bool>, bounds: &Bounds) -> Result<String, OcrError> {
+        let mut error = None;
+
+        // NB: letters like `I` have a blank first column, so the first
+        // letter might start up to a spacing to the left of the first column
+        // which has something drawn in it.
+        for shift in 0..self.spacing {
+            let e = match self.read(grid, bounds, shift) {
+                Ok(out) => return Ok(out),
+                Err(e) => e,
+            };
+
+            match (&error, &e) {
+                (
+                    Some(OcrError::UnknownGlyph { index: best, .. }),
+                    OcrError::UnknownGlyph { index, .. },
+                ) if index <= best => {}
+                _ => error = Some(e),
+            }
+        }
+
+        Err(error.unwrap_or(OcrError::Empty))
+    }
+
+    /// Read letters where the first one starts `shift` columns to the left of
+    /// the bounds.
+    fn read(&self, grid: &Grid<bool>, bounds: &Bounds, shift: usize) -> Result<String, OcrError> {
+        let start = bounds.x as isize - shift as isize;
+
+        let cell = |x: usize, y: usize| {
+            usize::try_from(start + x as isize)
+                .ok()
+                .and_then(|x| grid.get(x, bounds.y + y))
+                .copied()
+                .unwrap_or_default()
+        };
+
+        let mut out = String::new();
+
+        for index in 0..(bounds.width + shift).div_ceil(self.spacing) {
+            let column = index * self.spacing;
+
+            let found = self.glyphs.iter().find(|(_, rows)| {
+                rows.iter().enumerate().all(|(y, row)| {
+                    row.bytes()
+                        .enumerate()
+                        .all(|(x, b)| cell(column + x, y) == (b == b'#'))
+                })
+            });
+
+            // The spacing between letters must be blank, or the letter is
+            // wider than the font allows.
+            let spaced =
+                (self.width..self.spacing).all(|x| (0..self.height).all(|y| !cell(column + x, y)));
+
+            match found {
+                Some((c, _)) if spaced => out.push(*c),
+                _ => {
+                    let glyph =
+                        Grid::from_fn(self.spacing, self.height, |x, y| cell(column + x, y))
+                            .render(|on| if *on { '#' } else { '.' });
+
+                    return Err(OcrError::UnknownGlyph {
+                        index,
+                        column: (start + column as isize).max(0) as usize,
+                        glyph,
+                    });
+                }
+            }
+        }
+
+        Ok(out)
+    }
+}
+
+/// Recognise the letters in the given grid, picking the font by the height
+/// of what is drawn.
+///
+/// See [Font::recognize].
+pub fn recognize(grid: &Grid<bool>) -> Result<String, OcrError> {
+    let bounds = Bounds::of(grid).ok_or(OcrError::Empty)?;
+
+    match Font::with_height(bounds.height) {
+        Some(font) => font.recognize_in(grid, &bounds),
+        None => Err(OcrError::UnsupportedHeight(bounds.height)),
+    }
+}
+
+/// The region of a grid which has something drawn in it.
+struct Bounds {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+impl Bounds {
+    fn of(grid: &Grid<bool>) -> Option<Self> {
+        let rows = (0..grid.height())
+            .filter(|y| grid.row(*y).iter().any(|on| *on))
+            .collect::<Vec<_>>();
+        let columns = (0..grid.width())
+            .filter(|x| grid.column(*x).any(|on| *on))
+            .collect::<Vec<_>>();
+
+        let (y, y_end) = (*rows.first()?, *rows.last()?);
+        let (x, x_end) = (*columns.first()?, *columns.last()?);
+
+        Some(Self {
+            x,
+            y,
+            width: x_end - x + 1,
+            height: y_end - y + 1,
+        })
+    }
+}
+
+const SMALL_GLYPHS: &[(char, &[&str])] = &[
+    ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', &[".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+const LARGE_GLYPHS: &[(char, &[&str])] = &[
+    (
+        'A',
+        &[
+            "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'B',
+        &[
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+            "#....#", "#####.",
+        ],
+    ),
+    (
+        'C',
+        &[
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#....#", ".####.",
+        ],
+    ),
+    (
+        'E',
+        &[
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'F',
+        &[
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'G',
+        &[
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+            "#...##", ".###.#",
+        ],
+    ),
+    (
+        'H',
+        &[
+            "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'J',
+        &[
+            "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+            "#...#.", ".###..",
+        ],
+    ),
+    (
+        'K',
+        &[
+            "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+            "#...#.", "#....#",
+        ],
+    ),
+    (
+        'L',
+        &[
+            "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'N',
+        &[
+            "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+            "#...##", "#....#",
+        ],
+    ),
+    (
+        'P',
+        &[
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'R',
+        &[
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'X',
+        &[
+            "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'Z',
+        &[
+            "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+];
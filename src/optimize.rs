@@ -0,0 +1,76 @@
+//! Finding the integer positions which minimize a cost.
+
+use std::ops::RangeInclusive;
+
+/// Find the lowest cost of a convex function over the given range of
+/// positions through ternary search, returning the position and its cost.
+///
+/// The function is evaluated `O(log n)` times for a range of `n` positions.
+/// For the result to be correct the cost must never decrease after it has
+/// started to increase, otherwise a local minimum might be returned. If
+/// several positions share the lowest cost, the lowest one is returned.
+///
+/// Returns `None` if the range is empty.
+pub fn ternary_search<C, F>(range: RangeInclusive<i64>, mut cost: F) -> Option<(i64, C)>
+where
+    C: Ord,
+    F: FnMut(i64) -> C,
+{
+    let (mut lo, mut hi) = range.into_inner();
+
+    if lo > hi {
+        return None;
+    }
+
+    // NB: for a convex function, the cost stops decreasing at the first
+    // position where it's no higher than the cost of the next position.
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+
+        if cost(mid) <= cost(mid + 1) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+
+    Some((lo, cost(lo)))
+}
+
+/// Find the lowest cost by evaluating every position in the given range,
+/// returning the position and its cost.
+///
+/// This works for any cost function, but is linear in the size of the range.
+/// If several positions share the lowest cost, the lowest one is returned.
+///
+/// Returns `None` if the range is empty.
+pub fn scan<C, F>(range: RangeInclusive<i64>, mut cost: F) -> Option<(i64, C)>
+where
+    C: Ord,
+    F: FnMut(i64) -> C,
+{
+    range
+        .map(|n| (n, cost(n)))
+        .min_by(|(_, a), (_, b)| a.cmp(b))
+}
+
+/// The lower median of the given sorted values.
+///
+/// This is a position which minimizes the sum of distances to every value.
+///
+/// Returns `None` if there are no values.
+pub fn median(sorted: &[i64]) -> Option<i64> {
+    if sorted.is_empty() {
+        return None;
+    }
+
+    Some(sorted[(sorted.len() - 1) / 2])
+}
+
+/// The mean of the given values, rounded down.
+///
+/// Returns `None` if there are no values.
+pub fn mean(values: &[i64]) -> Option<i64> {
+    let len = i64::try_from(values.len()).ok().filter(|len| *len > 0)?;
+    Some(values.iter().sum::<i64>().div_euclid(len))
+}
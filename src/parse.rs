@@ -1,12 +1,17 @@
 use core::num;
+use std::error;
+use std::fmt;
 use std::io;
+use std::ops::Deref;
+use std::str::FromStr;
+use std::vec;
 
 use anyhow::Result;
 use thiserror::Error;
 
-/// An error raised during parsing.
+/// The kind of an error raised during parsing.
 #[derive(Debug, Error)]
-pub enum ParseError {
+pub enum ParseErrorKind {
     #[error("failed to parse int")]
     ParseInt(
         #[source]
@@ -29,6 +34,26 @@ pub enum ParseError {
     MissingItem,
     #[error("missing line")]
     MissingLine,
+    #[error("missing section")]
+    MissingSection,
+    #[error("expected {0}")]
+    Expected(String),
+    #[error("expected `{literal}` from pattern `{pattern}`")]
+    Literal {
+        pattern: &'static str,
+        literal: &'static str,
+    },
+    #[error("placeholder #{index} of pattern `{pattern}` failed to parse")]
+    Placeholder {
+        pattern: &'static str,
+        index: usize,
+        #[source]
+        error: Box<ParseError>,
+    },
+    #[error("trailing input")]
+    TrailingInput,
+    #[error("item parsed without consuming any input")]
+    NoProgress,
     #[error("failed to parse")]
     Custom(
         #[source]
@@ -37,16 +62,109 @@ pub enum ParseError {
     ),
 }
 
+/// Where in the input a [ParseError] happened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// The line number, starting at 1.
+    pub line: usize,
+    /// The column as a byte offset into the line, starting at 1.
+    pub column: usize,
+    /// The byte offset into the input.
+    pub offset: usize,
+    /// The text which failed to parse.
+    pub text: String,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)?;
+
+        if !self.text.is_empty() {
+            write!(f, ": `{}`", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An error raised during parsing.
+#[derive(Debug)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    location: Option<Box<Location>>,
+}
+
+impl ParseError {
+    /// The kind of the error.
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+
+    /// Where in the input the error happened, if known.
+    pub fn location(&self) -> Option<&Location> {
+        self.location.as_deref()
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.kind.fmt(f)?;
+
+        if let Some(location) = &self.location {
+            write!(f, " at {}", location)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        self.kind.source()
+    }
+}
+
+impl<T> From<T> for ParseError
+where
+    ParseErrorKind: From<T>,
+{
+    fn from(error: T) -> Self {
+        Self {
+            kind: ParseErrorKind::from(error),
+            location: None,
+        }
+    }
+}
+
 /// Parser helper.
 #[derive(Debug)]
 pub struct Parser<'a> {
+    /// The whole input being parsed, used to calculate locations.
+    source: &'a str,
+    /// The line number that the source starts at.
+    line: usize,
+    /// The remaining input.
     input: &'a str,
+    /// Separator used to split items, or `None` to split by whitespace.
+    sep: Option<&'a str>,
 }
 
 impl<'a> Parser<'a> {
     /// Parse lines.
     pub fn new(input: &'a str) -> Self {
-        Self { input }
+        Self::with_source(input, input, 0)
+    }
+
+    /// Construct a parser over the given input, which must be a subslice of
+    /// `source`. Locations are reported relative to the start of `source`,
+    /// which is on line `line` counting from zero.
+    pub(crate) fn with_source(source: &'a str, input: &'a str, line: usize) -> Self {
+        Self {
+            source,
+            line,
+            input,
+            sep: None,
+        }
     }
 
     /// Coerce into underlying string.
@@ -54,44 +172,280 @@ impl<'a> Parser<'a> {
         self.input
     }
 
+    /// Get the remaining input.
+    pub fn as_str(&self) -> &'a str {
+        self.input
+    }
+
+    /// Skip over the given number of bytes of input.
+    pub(crate) fn advance(&mut self, n: usize) {
+        self.input = &self.input[n..];
+    }
+
     /// Parse the next line as input.
-    pub fn parse<T: 'a>(&mut self) -> Result<T, ParseError>
+    ///
+    /// If parsing fails without indicating where, the error is located at the
+    /// input consumed while parsing.
+    pub fn parse<T>(&mut self) -> Result<T, ParseError>
     where
         T: Parseable<'a>,
     {
-        T::parse(self)
+        let start = self.input.trim_start();
+
+        match T::parse(self) {
+            Ok(value) => Ok(value),
+            Err(mut e) => {
+                if e.location.is_none() {
+                    let len = start.len() - self.input.len().min(start.len());
+                    e.location = Some(Box::new(self.locate(&start[..len])));
+                }
+
+                Err(e)
+            }
+        }
+    }
+
+    /// Construct an error located at the given text, which must be a subslice
+    /// of the input.
+    pub fn error_at<E>(&self, text: &str, error: E) -> ParseError
+    where
+        ParseErrorKind: From<E>,
+    {
+        ParseError {
+            kind: ParseErrorKind::from(error),
+            location: Some(Box::new(self.locate(text))),
+        }
+    }
+
+    /// Construct an error located at the current position.
+    pub fn error<E>(&self, error: E) -> ParseError
+    where
+        ParseErrorKind: From<E>,
+    {
+        self.error_at(&self.input[..0], error)
+    }
+
+    /// Calculate the location of the given text.
+    fn locate(&self, text: &str) -> Location {
+        let offset = (text.as_ptr() as usize)
+            .saturating_sub(self.source.as_ptr() as usize)
+            .min(self.source.len());
+
+        let before = &self.source[..offset];
+        let line = self.line + before.matches('\n').count() + 1;
+        let column = offset - before.rfind('\n').map_or(0, |n| n + 1) + 1;
+
+        Location {
+            line,
+            column,
+            offset,
+            text: text.to_owned(),
+        }
+    }
+
+    /// Test if there is no more input to parse.
+    pub fn is_empty(&self) -> bool {
+        self.input.trim().is_empty()
+    }
+
+    /// Get the next item and construct a parser which splits it into items
+    /// by the given separator instead of by whitespace.
+    pub fn split(&mut self, sep: &'a str) -> Result<Parser<'a>, ParseError> {
+        let item = self.item()?;
+        let mut p = self.sub(item);
+        p.sep = Some(sep);
+        Ok(p)
+    }
+
+    /// Expect the next item to be the given literal.
+    pub fn expect_item(&mut self, lit: &str) -> Result<(), ParseError> {
+        match self.next_item() {
+            Some(item) if item == lit => Ok(()),
+            Some(item) => Err(self.error_at(item, ParseErrorKind::Expected(format!("`{}`", lit)))),
+            None => Err(self.error(ParseErrorKind::Expected(format!("`{}`", lit)))),
+        }
+    }
+
+    /// Peek the next character without consuming it.
+    pub fn peek_char(&self) -> Option<char> {
+        self.input.chars().next()
+    }
+
+    /// Get the next character.
+    pub fn next_char(&mut self) -> Option<char> {
+        let c = self.peek_char()?;
+        self.advance(c.len_utf8());
+        Some(c)
+    }
+
+    /// Parse the next character as a decimal digit.
+    pub fn digit(&mut self) -> Result<u32, ParseError> {
+        match self.peek_char().and_then(|c| c.to_digit(10)) {
+            Some(d) => {
+                self.advance(1);
+                Ok(d)
+            }
+            None => {
+                Err(self.error_at(self.peek(1), ParseErrorKind::Expected("a digit".to_owned())))
+            }
+        }
+    }
+
+    /// Expect the input to continue with the given literal.
+    pub fn expect(&mut self, lit: &str) -> Result<(), ParseError> {
+        if !self.input.starts_with(lit) {
+            let text = self.peek(lit.chars().count());
+            return Err(self.error_at(text, ParseErrorKind::Expected(format!("`{}`", lit))));
+        }
+
+        self.advance(lit.len());
+        Ok(())
+    }
+
+    /// Consume characters while the predicate holds, returning the consumed
+    /// input.
+    pub fn take_while<P>(&mut self, mut predicate: P) -> &'a str
+    where
+        P: FnMut(char) -> bool,
+    {
+        let n = self
+            .input
+            .find(|c| !predicate(c))
+            .unwrap_or(self.input.len());
+
+        let (taken, rest) = self.input.split_at(n);
+        self.input = rest;
+        taken
+    }
+
+    /// Parse a number made up of an optional sign followed by decimal digits.
+    pub fn number<T>(&mut self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        ParseErrorKind: From<T::Err>,
+    {
+        let input = self.input;
+        let sign = usize::from(input.starts_with(['-', '+']));
+
+        let digits = input[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(input.len() - sign);
+
+        if digits == 0 {
+            return Err(self.error_at::<ParseErrorKind>(
+                self.peek(sign + 1),
+                ParseErrorKind::Expected("a number".to_owned()),
+            ));
+        }
+
+        let text = &input[..sign + digits];
+        self.advance(text.len());
+        text.parse().map_err(|e| self.error_at(text, e))
+    }
+
+    /// Get up to the given number of characters from the input without
+    /// consuming them.
+    pub(crate) fn peek(&self, chars: usize) -> &'a str {
+        let n = self
+            .input
+            .char_indices()
+            .nth(chars)
+            .map_or(self.input.len(), |(n, _)| n);
+
+        &self.input[..n]
     }
 
-    /// Get the next item as split by whitespace.
+    /// Get the next item as split by whitespace, or by the separator if one
+    /// is configured.
     pub fn item(&mut self) -> Result<&'a str, ParseError> {
-        self.next_item().ok_or_else(|| ParseError::MissingItem)
+        match self.next_item() {
+            Some(item) => Ok(item),
+            None => Err(self.error(ParseErrorKind::MissingItem)),
+        }
     }
 
     /// Get the next line.
     pub fn line(&mut self) -> Result<Parser<'a>, ParseError> {
-        let p = self.next_line().ok_or_else(|| ParseError::MissingLine)?;
-
-        Ok(p)
+        match self.next_line() {
+            Some(p) => Ok(p),
+            None => Err(self.error(ParseErrorKind::MissingLine)),
+        }
     }
 
     /// Get the next line.
     pub fn next_line(&mut self) -> Option<Parser<'a>> {
         if let Some((part, rest)) = self.input.split_once('\n') {
             self.input = rest;
-            return Some(Parser { input: part.trim() });
+            return Some(self.sub(part.trim()));
         }
 
-        let input = std::mem::take(&mut self.input);
+        let input = self.take();
 
         if !input.is_empty() {
-            return Some(Parser { input });
+            return Some(self.sub(input.trim()));
         }
 
         None
     }
 
+    /// Get the next section of lines, as separated by blank lines.
+    pub fn section(&mut self) -> Result<Parser<'a>, ParseError> {
+        match self.next_section() {
+            Some(p) => Ok(p),
+            None => Err(self.error(ParseErrorKind::MissingSection)),
+        }
+    }
+
+    /// Get the next section of lines, as separated by blank lines.
+    pub fn next_section(&mut self) -> Option<Parser<'a>> {
+        let input = self.input;
+        let mut start = None;
+        let mut offset = 0;
+
+        for line in input.split_inclusive('\n') {
+            match (start, line.trim().is_empty()) {
+                (None, false) => {
+                    start = Some(offset);
+                }
+                (Some(start), true) => {
+                    self.input = &input[offset..];
+                    return Some(self.sub(input[start..offset].trim_end()));
+                }
+                _ => {}
+            }
+
+            offset += line.len();
+        }
+
+        self.take();
+        let start = start?;
+        Some(self.sub(input[start..].trim_end()))
+    }
+
+    /// Iterate over the remaining sections of lines, as separated by blank
+    /// lines.
+    pub fn sections(&mut self) -> impl Iterator<Item = Parser<'a>> + '_ {
+        std::iter::from_fn(move || self.next_section())
+    }
+
     /// Parse the next item or raise an error.
     pub fn next_item(&mut self) -> Option<&'a str> {
+        if let Some(sep) = self.sep {
+            if self.input.is_empty() {
+                return None;
+            }
+
+            let part = match self.input.split_once(sep) {
+                Some((part, rest)) => {
+                    self.input = rest;
+                    part
+                }
+                None => self.take(),
+            };
+
+            return Some(part.trim());
+        }
+
         if let Some((part, rest)) = self
             .input
             .trim_start_matches(char::is_whitespace)
@@ -101,7 +455,7 @@ impl<'a> Parser<'a> {
             return Some(part);
         }
 
-        let s = std::mem::take(&mut self.input);
+        let s = self.take().trim();
 
         if !s.is_empty() {
             return Some(s);
@@ -109,15 +463,34 @@ impl<'a> Parser<'a> {
 
         None
     }
+
+    /// Take the rest of the input, leaving an empty input positioned at its
+    /// end so that locations are still correct.
+    fn take(&mut self) -> &'a str {
+        let (input, rest) = self.input.split_at(self.input.len());
+        self.input = rest;
+        input
+    }
+
+    /// Construct a parser over a subslice of the input.
+    pub(crate) fn sub(&self, input: &'a str) -> Parser<'a> {
+        Parser::with_source(self.source, input, self.line)
+    }
+}
+
+impl<'a> From<&'a str> for Parser<'a> {
+    fn from(input: &'a str) -> Self {
+        Self::new(input)
+    }
 }
 
 /// Parse a single line of input into the given output.
-pub fn parse<'de, T: 'de>(line: &'de str) -> Result<T, ParseError>
+pub fn parse<'de, T>(line: &'de str) -> Result<T, ParseError>
 where
-    T: Parseable<'de>,
+    T: 'de + Parseable<'de>,
 {
-    let mut p = Parser { input: line };
-    T::parse(&mut p)
+    let mut p = Parser::new(line);
+    p.parse()
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -136,6 +509,21 @@ impl<'de> Parseable<'de> for Skip {
     }
 }
 
+impl Parseable<'_> for char {
+    fn parse(p: &mut Parser<'_>) -> Result<Self, ParseError> {
+        let item = p.item()?;
+        let mut chars = item.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(p.error_at(
+                item,
+                ParseErrorKind::Expected("a single character".to_owned()),
+            )),
+        }
+    }
+}
+
 impl<'de> Parseable<'de> for &'de str {
     fn parse(p: &mut Parser<'de>) -> Result<Self, ParseError> {
         p.item()
@@ -147,7 +535,7 @@ macro_rules! parse_int {
         impl Parseable<'_> for $ty {
             fn parse(p: &mut Parser<'_>) -> Result<Self, ParseError> {
                 let item = p.item()?;
-                Ok(item.parse()?)
+                item.parse().map_err(|e| p.error_at(item, e))
             }
         }
     };
@@ -175,8 +563,8 @@ macro_rules! parse_tuple {
     ($first_ty:ident $first_var:ident $(, $ty:ident $var:ident)*) => {
         impl<'de, $first_ty, $($ty,)*> Parseable<'de> for ($first_ty, $($ty,)*) where $first_ty: Parseable<'de>, $($ty: Parseable<'de>),* {
             fn parse(p: &mut Parser<'de>) -> Result<Self, ParseError> {
-                let $first_var = $first_ty::parse(p)?;
-                $(let $var = $ty::parse(p)?;)*
+                let $first_var = p.parse::<$first_ty>()?;
+                $(let $var = p.parse::<$ty>()?;)*
                 Ok(($first_var, $($var,)*))
             }
         }
@@ -197,9 +585,139 @@ where
         let mut init = [T::default(); N];
 
         for out in init.iter_mut() {
-            *out = T::parse(p)?;
+            *out = p.parse::<T>()?;
         }
 
         Ok(init)
     }
 }
+
+/// Parses items until the end of input, which is usually the rest of the
+/// line. Fails if an item doesn't consume any input.
+impl<'de, T> Parseable<'de> for Vec<T>
+where
+    T: Parseable<'de>,
+{
+    fn parse(p: &mut Parser<'de>) -> Result<Self, ParseError> {
+        let mut out = Vec::new();
+
+        while !p.is_empty() {
+            let len = p.as_str().len();
+            out.push(p.parse::<T>()?);
+
+            // NB: an item which consumes nothing would be parsed forever.
+            if p.as_str().len() == len {
+                return Err(p.error_at(p.as_str().trim(), ParseErrorKind::NoProgress));
+            }
+        }
+
+        Ok(out)
+    }
+}
+
+/// Parses an item if there is any input left.
+impl<'de, T> Parseable<'de> for Option<T>
+where
+    T: Parseable<'de>,
+{
+    fn parse(p: &mut Parser<'de>) -> Result<Self, ParseError> {
+        if p.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(p.parse::<T>()?))
+    }
+}
+
+/// A fixed number of sections of lines separated by blank lines, where each
+/// element of the tuple `T` is parsed from its own section.
+///
+/// Each section must be fully consumed by the element parsed from it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sections<T>(pub T);
+
+macro_rules! parse_sections {
+    ($($ty:ident $var:ident),*) => {
+        impl<'de, $($ty,)*> Parseable<'de> for Sections<($($ty,)*)> where $($ty: Parseable<'de>),* {
+            fn parse(p: &mut Parser<'de>) -> Result<Self, ParseError> {
+                $(let $var = parse_section::<$ty>(p)?;)*
+                Ok(Sections(($($var,)*)))
+            }
+        }
+    };
+}
+
+parse_sections!(A a);
+parse_sections!(A a, B b);
+parse_sections!(A a, B b, C c);
+parse_sections!(A a, B b, C c, D d);
+
+fn parse_section<'de, T>(p: &mut Parser<'de>) -> Result<T, ParseError>
+where
+    T: Parseable<'de>,
+{
+    let mut section = p.section()?;
+    let value = section.parse::<T>()?;
+
+    if !section.is_empty() {
+        let rest = section.as_str().trim();
+        return Err(section.error_at(rest, ParseErrorKind::TrailingInput));
+    }
+
+    Ok(value)
+}
+
+/// A collection of items separated by `SEP`, like `3,4,3,1,2`.
+///
+/// This consumes the rest of the input, so it's typically used on a single
+/// line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Delimited<T, const SEP: char>(pub Vec<T>);
+
+impl<T, const SEP: char> Delimited<T, SEP> {
+    /// Coerce into the parsed items.
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T, const SEP: char> Deref for Delimited<T, SEP> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const SEP: char> IntoIterator for Delimited<T, SEP> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'de, T, const SEP: char> Parseable<'de> for Delimited<T, SEP>
+where
+    T: Parseable<'de>,
+{
+    fn parse(p: &mut Parser<'de>) -> Result<Self, ParseError> {
+        let input = p.as_str().trim();
+        let mut out = Vec::new();
+
+        if !input.is_empty() {
+            for part in input.split(SEP) {
+                let mut sub = p.sub(part);
+                out.push(sub.parse::<T>()?);
+
+                if !sub.is_empty() {
+                    return Err(sub.error_at(sub.as_str().trim(), ParseErrorKind::TrailingInput));
+                }
+            }
+        }
+
+        p.advance(p.as_str().len());
+        Ok(Self(out))
+    }
+}
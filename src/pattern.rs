@@ -0,0 +1,99 @@
+use crate::{ParseError, ParseErrorKind, Parseable, Parser};
+
+/// Matches input against a pattern like `{},{} -> {},{}`.
+///
+/// This is used by the code generated by [parse_pattern!][crate::parse_pattern],
+/// which splits the pattern into literals and placeholders at compile time.
+pub struct Pattern<'a> {
+    pattern: &'static str,
+    parser: Parser<'a>,
+}
+
+impl<'a> Pattern<'a> {
+    /// Construct a new pattern matching over the input of the given parser.
+    pub fn new(pattern: &'static str, parser: Parser<'a>) -> Self {
+        Self { pattern, parser }
+    }
+
+    /// Match the given literal.
+    pub fn literal(&mut self, literal: &'static str) -> Result<(), ParseError> {
+        let input = self.parser.as_str();
+
+        if !input.starts_with(literal) {
+            let text = self.parser.peek(literal.chars().count());
+
+            return Err(self.parser.error_at(
+                text,
+                ParseErrorKind::Literal {
+                    pattern: self.pattern,
+                    literal,
+                },
+            ));
+        }
+
+        self.parser.advance(literal.len());
+        Ok(())
+    }
+
+    /// Parse the placeholder with the given index counting from 1, which
+    /// extends up until the next literal in the pattern or to the end of input
+    /// if there is none.
+    pub fn placeholder<T>(
+        &mut self,
+        index: usize,
+        until: Option<&'static str>,
+    ) -> Result<T, ParseError>
+    where
+        T: Parseable<'a>,
+    {
+        let input = self.parser.as_str();
+
+        let text = match until {
+            Some(literal) => match input.find(literal) {
+                Some(n) => &input[..n],
+                None => {
+                    return Err(self.parser.error_at(
+                        input,
+                        ParseErrorKind::Literal {
+                            pattern: self.pattern,
+                            literal,
+                        },
+                    ))
+                }
+            },
+            None => input,
+        };
+
+        let mut p = self.parser.sub(text);
+
+        let result = match p.parse::<T>() {
+            Ok(value) if p.is_empty() => Ok(value),
+            Ok(..) => Err(p.error_at(p.as_str().trim(), ParseErrorKind::TrailingInput)),
+            Err(error) => Err(error),
+        };
+
+        let value = result.map_err(|error| {
+            self.parser.error_at(
+                text,
+                ParseErrorKind::Placeholder {
+                    pattern: self.pattern,
+                    index,
+                    error: Box::new(error),
+                },
+            )
+        })?;
+
+        self.parser.advance(text.len());
+        Ok(value)
+    }
+
+    /// Finish matching, making sure that all input has been consumed.
+    pub fn finish(self) -> Result<(), ParseError> {
+        if !self.parser.is_empty() {
+            let input = self.parser.as_str().trim();
+            return Err(self.parser.error_at(input, ParseErrorKind::TrailingInput));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,550 @@
+//! The command line runner used by the `aoc` binary and the per-day binaries.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use anyhow::{anyhow, bail, Context, Result};
+
+use crate::bench::{self, Bench};
+use crate::days::{self, Day};
+use crate::expected::{Expected, Part, Status, EXPECTED};
+use crate::{LoadError, Loader, DEFAULT_PROFILE};
+
+const USAGE: &str =
+    "usage: aoc run [--record] [--time] [--profile <name>]... [--all-profiles] <days>...
+       aoc bench [--runs <n>] [--json <path>] [--profile <name>]... [--all-profiles] <days>...
+
+Where <days> is one of:
+  5        a single day
+  1..18    a half-open range of days
+  1..=18   an inclusive range of days
+  all      every available day
+
+Options:
+  --record            save answers which are not yet known as the expected answers
+  --time              report how long parsing and each part took
+  --runs <n>          the number of times to run each day when benchmarking,
+                      defaults to 10
+  --json <path>       write benchmark results as JSON to the given path
+  --profile <name>    run against the input of the given profile, stored in
+                      input/<name>/, defaults to the input stored in input/
+  --all-profiles      run against the input of every available profile, days
+                      without input for a profile are skipped";
+
+/// Entrypoint of the `aoc` binary.
+pub fn main() -> ExitCode {
+    exit(run(env::args().skip(1)))
+}
+
+/// Entrypoint of a per-day binary.
+///
+/// The first command line argument is used as the input if present, in which
+/// case answers can't be checked.
+pub fn day(day: u32) -> ExitCode {
+    let outcome = (|| {
+        let day = days::get(day).ok_or_else(|| anyhow!("day {} is not available", day))?;
+        let loader = Loader::from_args();
+        let mut runner = Runner::new(load_expected(&loader)?);
+        runner.run(&loader, day);
+        Ok(runner.finish())
+    })();
+
+    exit(outcome)
+}
+
+fn exit(outcome: Result<bool>) -> ExitCode {
+    match outcome {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {:?}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Options parsed from the command line.
+struct Options {
+    record: bool,
+    time: bool,
+    all_profiles: bool,
+    profiles: Vec<String>,
+    runs: usize,
+    json: Option<PathBuf>,
+    days: Vec<&'static Day>,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self> {
+        let mut options = Options {
+            record: false,
+            time: false,
+            all_profiles: false,
+            profiles: Vec::new(),
+            runs: 10,
+            json: None,
+            days: Vec::new(),
+        };
+
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| {
+                args.next()
+                    .ok_or_else(|| anyhow!("missing argument to {}", name))
+            };
+
+            match arg.as_str() {
+                "--record" => options.record = true,
+                "--time" => options.time = true,
+                "--all-profiles" => options.all_profiles = true,
+                "--profile" => options.profiles.push(value("--profile")?),
+                "--runs" => {
+                    options.runs = value("--runs")?.parse().context("bad --runs")?;
+
+                    if options.runs == 0 {
+                        bail!("--runs must be at least 1");
+                    }
+                }
+                "--json" => options.json = Some(PathBuf::from(value("--json")?)),
+                _ => {
+                    for day in parse_days(&arg).with_context(|| anyhow!("bad day `{}`", arg))? {
+                        if !options.days.iter().any(|d| d.day == day.day) {
+                            options.days.push(day);
+                        }
+                    }
+                }
+            }
+        }
+
+        if options.days.is_empty() {
+            bail!("no days specified\n\n{}", USAGE);
+        }
+
+        Ok(options)
+    }
+
+    /// Construct loaders for every selected profile.
+    fn loaders(&self, loader: &Loader) -> Result<Vec<Loader>> {
+        let mut profiles = self.profiles.clone();
+
+        if self.all_profiles {
+            profiles.extend(loader.profiles()?);
+        }
+
+        if profiles.is_empty() {
+            profiles.push(DEFAULT_PROFILE.to_owned());
+        }
+
+        let mut loaders = Vec::new();
+
+        for (n, profile) in profiles.iter().enumerate() {
+            if !profiles[..n].contains(profile) {
+                loaders.push(loader.clone().with_profile(profile));
+            }
+        }
+
+        Ok(loaders)
+    }
+}
+
+/// Run the command line, returning `false` if any day failed.
+fn run(mut args: impl Iterator<Item = String>) -> Result<bool> {
+    let command = match args.next() {
+        Some(command) => command,
+        None => bail!("missing command\n\n{}", USAGE),
+    };
+
+    match command.as_str() {
+        "run" | "bench" => (),
+        "help" | "--help" | "-h" => {
+            println!("{}", USAGE);
+            return Ok(true);
+        }
+        command => bail!("unsupported command `{}`\n\n{}", command, USAGE),
+    }
+
+    let options = Options::parse(args)?;
+    let loader = Loader::new();
+    let loaders = options.loaders(&loader)?;
+
+    if command == "bench" {
+        return bench(&options, &loaders);
+    }
+
+    let mut runner = Runner::new(load_expected(&loader)?);
+    runner.skip_missing = options.all_profiles;
+    runner.time = options.time;
+
+    for loader in &loaders {
+        for day in &options.days {
+            runner.run(loader, day);
+        }
+    }
+
+    if options.record {
+        runner.record()?;
+    }
+
+    Ok(runner.finish())
+}
+
+/// Benchmark the selected days by solving them repeatedly.
+fn bench(options: &Options, loaders: &[Loader]) -> Result<bool> {
+    let mut ok = true;
+    let mut benches = Vec::new();
+
+    for loader in loaders {
+        let profile = loader.profile().unwrap_or(DEFAULT_PROFILE);
+
+        for day in &options.days {
+            let prefix = Prefix(Some(profile), day.day);
+
+            let input = match loader.load(day.input) {
+                Ok(input) => input,
+                Err(LoadError::NotFound { .. }) if options.all_profiles => {
+                    println!("{}: skipped: no input", prefix);
+                    continue;
+                }
+                Err(e) => {
+                    println!("{}: failed: {:?}", prefix, anyhow::Error::from(e));
+                    ok = false;
+                    continue;
+                }
+            };
+
+            let outcome = (|| {
+                let mut timings = Vec::with_capacity(options.runs);
+
+                for _ in 0..options.runs {
+                    timings.push(day.solve(&input)?.timings);
+                }
+
+                Bench::new(profile, day.day, &timings).ok_or_else(|| anyhow!("no runs"))
+            })();
+
+            match outcome {
+                Ok(bench) => {
+                    println!("{}: parse: {}", prefix, bench.parse);
+                    println!("{}: part 1: {}", prefix, bench.part1);
+                    println!("{}: part 2: {}", prefix, bench.part2);
+                    println!("{}: total: {}", prefix, bench.total);
+                    benches.push(bench);
+                }
+                Err(e) => {
+                    println!("{}: failed: {:?}", prefix, e);
+                    ok = false;
+                }
+            }
+        }
+    }
+
+    if let Some(path) = &options.json {
+        fs::write(path, bench::to_json(&benches))
+            .with_context(|| anyhow!("failed to write `{}`", path.display()))?;
+        println!("wrote results to `{}`", path.display());
+    }
+
+    Ok(ok)
+}
+
+/// Load the expected answers stored in each input directory, if there are
+/// any.
+///
+/// Answers are only checked against the ones stored next to the input, since
+/// another directory might hold different input for the same profile.
+fn load_expected(loader: &Loader) -> Result<BTreeMap<PathBuf, Expected>> {
+    let mut out = BTreeMap::new();
+
+    for dir in loader.dirs() {
+        let path = dir.join(EXPECTED);
+
+        if path.is_file() && !out.contains_key(dir) {
+            out.insert(dir.clone(), Expected::read(&path)?);
+        }
+    }
+
+    Ok(out)
+}
+
+/// An answer which isn't known yet.
+struct Unknown {
+    /// The input directory and profile the answer belongs to, if known.
+    origin: Option<(PathBuf, String)>,
+    day: u32,
+    part: Part,
+    answer: String,
+}
+
+/// Runs days and keeps track of how their answers compare to the expected
+/// ones.
+struct Runner {
+    /// Expected answers by the input directory they are stored in.
+    expected: BTreeMap<PathBuf, Expected>,
+    color: bool,
+    /// Skip days which have no input, rather than treating them as failed.
+    skip_missing: bool,
+    /// Report how long each day took.
+    time: bool,
+    correct: usize,
+    wrong: usize,
+    unknown: Vec<Unknown>,
+    /// Answers which were unknown and have been saved as expected answers.
+    recorded: usize,
+    failed: usize,
+    skipped: usize,
+}
+
+impl Runner {
+    fn new(expected: BTreeMap<PathBuf, Expected>) -> Self {
+        Self {
+            expected,
+            color: env::var_os("NO_COLOR").is_none() && io::stdout().is_terminal(),
+            skip_missing: false,
+            time: false,
+            correct: 0,
+            wrong: 0,
+            unknown: Vec::new(),
+            recorded: 0,
+            failed: 0,
+            skipped: 0,
+        }
+    }
+
+    /// Run a single day and report its answers.
+    fn run(&mut self, loader: &Loader, day: &Day) {
+        let profile = loader.profile();
+        let prefix = Prefix(profile, day.day);
+
+        let input = match loader.load(day.input) {
+            Ok(input) => input,
+            Err(LoadError::NotFound { .. }) if self.skip_missing => {
+                println!("{}: {}: no input", prefix, self.paint(YELLOW, "skipped"));
+                self.skipped += 1;
+                return;
+            }
+            Err(e) => {
+                println!(
+                    "{}: {}: {:?}",
+                    prefix,
+                    self.paint(RED, "failed"),
+                    anyhow::Error::from(e)
+                );
+                self.failed += 1;
+                return;
+            }
+        };
+
+        let answers = match day.solve(&input) {
+            Ok(answers) => answers,
+            Err(e) => {
+                println!("{}: {}: {:?}", prefix, self.paint(RED, "failed"), e);
+                self.failed += 1;
+                return;
+            }
+        };
+
+        let origin = match (profile, loader.find_dir(day.input)) {
+            (Some(profile), Ok(dir)) => Some((dir, profile)),
+            _ => None,
+        };
+
+        let expected = origin.and_then(|(dir, _)| self.expected.get(dir));
+
+        for part in Part::ALL {
+            let answer = answers.get(part);
+
+            let status = match (origin, expected) {
+                (Some((_, profile)), Some(expected)) => {
+                    expected.check(profile, day.day, part, answer)
+                }
+                _ => Status::Unknown,
+            };
+
+            let status = match status {
+                Status::Correct => {
+                    self.correct += 1;
+                    self.paint(GREEN, "correct").to_string()
+                }
+                Status::Wrong(expected) => {
+                    self.wrong += 1;
+                    let expected = Multiline(expected).to_string();
+                    format!("{}, expected {}", self.paint(RED, "wrong"), expected)
+                }
+                Status::Unknown => {
+                    self.unknown.push(Unknown {
+                        origin: origin.map(|(dir, p)| (dir.to_owned(), p.to_owned())),
+                        day: day.day,
+                        part,
+                        answer: answer.to_owned(),
+                    });
+                    self.paint(YELLOW, "unknown").to_string()
+                }
+            };
+
+            println!("{}: {}: {} ({})", prefix, part, Multiline(answer), status);
+        }
+
+        if self.time {
+            let t = &answers.timings;
+            println!(
+                "{}: parse {:.2?}, part 1 {:.2?}, part 2 {:.2?}, total {:.2?}",
+                prefix,
+                t.parse,
+                t.part1,
+                t.part2,
+                t.total()
+            );
+        }
+    }
+
+    /// Save all unknown answers as expected answers, next to the input they
+    /// were produced from.
+    fn record(&mut self) -> Result<()> {
+        let mut recorded = BTreeMap::<&Path, usize>::new();
+
+        for unknown in &self.unknown {
+            let (dir, profile) = match &unknown.origin {
+                Some(origin) => origin,
+                None => bail!("can only record answers for known profiles"),
+            };
+
+            self.expected.entry(dir.clone()).or_default().insert(
+                profile,
+                unknown.day,
+                unknown.part,
+                &unknown.answer,
+            );
+
+            *recorded.entry(dir).or_default() += 1;
+        }
+
+        for (dir, count) in recorded {
+            let path = dir.join(EXPECTED);
+            self.expected[dir].write(&path)?;
+            println!("recorded {} answer(s) in `{}`", count, path.display());
+        }
+
+        self.recorded += self.unknown.len();
+        self.unknown.clear();
+        Ok(())
+    }
+
+    /// Print a summary, returning `true` if nothing was wrong or failed.
+    fn finish(&self) -> bool {
+        println!(
+            "{} correct, {} wrong, {} unknown, {} recorded, {} failed, {} skipped",
+            self.paint(GREEN, self.correct),
+            self.paint(RED, self.wrong),
+            self.paint(YELLOW, self.unknown.len()),
+            self.paint(YELLOW, self.recorded),
+            self.paint(RED, self.failed),
+            self.paint(YELLOW, self.skipped),
+        );
+
+        self.wrong == 0 && self.failed == 0
+    }
+
+    fn paint<T>(&self, color: &'static str, value: T) -> Paint<T> {
+        Paint {
+            color: self.color.then_some(color),
+            value,
+        }
+    }
+}
+
+/// Parse a specification of days.
+fn parse_days(arg: &str) -> Result<Vec<&'static Day>> {
+    if arg == "all" {
+        return Ok(days::DAYS.iter().collect());
+    }
+
+    let range = parse_range(arg)?;
+    let mut out = Vec::new();
+
+    for n in range {
+        out.push(days::get(n).ok_or_else(|| anyhow!("day {} is not available", n))?);
+    }
+
+    Ok(out)
+}
+
+/// Parse a single day or a range of days.
+fn parse_range(arg: &str) -> Result<RangeInclusive<u32>> {
+    let (start, end) = if let Some((start, end)) = arg.split_once("..=") {
+        (start.parse::<u32>()?, end.parse::<u32>()?)
+    } else if let Some((start, end)) = arg.split_once("..") {
+        let (start, end) = (start.parse::<u32>()?, end.parse::<u32>()?);
+
+        if start >= end {
+            bail!("invalid range `{}`", arg);
+        }
+
+        (start, end - 1)
+    } else {
+        let day = arg.parse()?;
+        (day, day)
+    };
+
+    if start > end {
+        bail!("invalid range `{}`", arg);
+    }
+
+    Ok(start..=end)
+}
+
+const GREEN: &str = "32";
+const RED: &str = "31";
+const YELLOW: &str = "33";
+
+/// Helper to print a value in the given ANSI color.
+struct Paint<T> {
+    color: Option<&'static str>,
+    value: T,
+}
+
+impl<T> fmt::Display for Paint<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.color {
+            Some(color) => write!(f, "\x1b[{}m{}\x1b[0m", color, self.value),
+            None => self.value.fmt(f),
+        }
+    }
+}
+
+/// Helper to print which profile and day a line of output belongs to.
+///
+/// The profile is only printed if it's not the default profile.
+struct Prefix<'a>(Option<&'a str>, u32);
+
+impl fmt::Display for Prefix<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(profile) if profile != DEFAULT_PROFILE => {
+                write!(f, "{}: day {:02}", profile, self.1)
+            }
+            _ => write!(f, "day {:02}", self.1),
+        }
+    }
+}
+
+/// Helper to print an answer which spans multiple lines on lines of its own.
+struct Multiline<'a>(&'a str);
+
+impl fmt::Display for Multiline<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.contains('\n') {
+            writeln!(f)?;
+            self.0.trim_end().fmt(f)
+        } else {
+            self.0.fmt(f)
+        }
+    }
+}
@@ -0,0 +1,231 @@
+//! Generic graph searches over a user-supplied neighbour function.
+//!
+//! Nodes can be anything which is `Clone + Eq + Hash`, like grid positions or
+//! interned names. Every search which finds its goal returns a [Found], with
+//! the cost, the full path from the start to the goal, and [Stats] on how
+//! much of the graph was explored.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Statistics on how much of the graph was explored by a search.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    /// The number of nodes which had their neighbours expanded.
+    pub expanded: usize,
+    /// The number of distinct nodes which were discovered.
+    pub discovered: usize,
+}
+
+/// The outcome of a search which reached its goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found<N, C> {
+    /// The total cost of the path.
+    pub cost: C,
+    /// The path from the start to the goal, including both.
+    pub path: Vec<N>,
+    /// Statistics on the search.
+    pub stats: Stats,
+}
+
+/// Nodes discovered during a search, which are referenced by index to avoid
+/// having to clone them.
+struct Arena<N, C> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    /// The best known cost and parent of every node.
+    best: Vec<(C, Option<usize>)>,
+}
+
+impl<N, C> Arena<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord,
+{
+    fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            index: HashMap::new(),
+            best: Vec::new(),
+        }
+    }
+
+    /// Offer a node with the given cost, returning its index if it's new or if
+    /// the cost is an improvement over the best known one.
+    fn offer(&mut self, node: N, cost: C, parent: Option<usize>) -> Option<usize> {
+        match self.index.entry(node) {
+            Entry::Occupied(e) => {
+                let index = *e.get();
+
+                if self.best[index].0 <= cost {
+                    return None;
+                }
+
+                self.best[index] = (cost, parent);
+                Some(index)
+            }
+            Entry::Vacant(e) => {
+                let index = self.nodes.len();
+                self.nodes.push(e.key().clone());
+                self.best.push((cost, parent));
+                e.insert(index);
+                Some(index)
+            }
+        }
+    }
+
+    /// Reconstruct the path leading up to the given node.
+    fn found(self, mut index: usize, expanded: usize) -> Found<N, C> {
+        let cost = self.best[index].0;
+        let mut path = vec![self.nodes[index].clone()];
+
+        while let Some(parent) = self.best[index].1 {
+            path.push(self.nodes[parent].clone());
+            index = parent;
+        }
+
+        path.reverse();
+
+        Found {
+            cost,
+            path,
+            stats: Stats {
+                expanded,
+                discovered: self.nodes.len(),
+            },
+        }
+    }
+}
+
+/// Breadth-first search for the shortest path from `start` to a node matching
+/// `goal`, where the cost is the number of steps taken.
+pub fn bfs<N, I, F, G>(start: N, mut neighbours: F, mut goal: G) -> Option<Found<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    let mut arena = Arena::new();
+    let mut queue = VecDeque::new();
+    let mut expanded = 0;
+
+    queue.extend(arena.offer(start, 0, None));
+
+    while let Some(index) = queue.pop_front() {
+        if goal(&arena.nodes[index]) {
+            return Some(arena.found(index, expanded));
+        }
+
+        expanded += 1;
+        let cost = arena.best[index].0 + 1;
+
+        for next in neighbours(&arena.nodes[index]) {
+            // NB: nodes are discovered in order of increasing cost, so a node
+            // which has already been seen can never be improved upon.
+            if arena.index.contains_key(&next) {
+                continue;
+            }
+
+            queue.extend(arena.offer(next, cost, Some(index)));
+        }
+    }
+
+    None
+}
+
+/// Find every node reachable from `start` in breadth-first order, including
+/// `start` itself.
+pub fn reachable<N, I, F>(start: N, mut neighbours: F) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut arena = Arena::<N, usize>::new();
+    let mut queue = VecDeque::new();
+
+    queue.extend(arena.offer(start, 0, None));
+
+    while let Some(index) = queue.pop_front() {
+        for next in neighbours(&arena.nodes[index]) {
+            if arena.index.contains_key(&next) {
+                continue;
+            }
+
+            queue.extend(arena.offer(next, 0, Some(index)));
+        }
+    }
+
+    arena.nodes
+}
+
+/// Dijkstra's algorithm for the lowest cost path from `start` to a node
+/// matching `goal`, where `neighbours` returns each neighbour together with
+/// the cost of moving to it.
+pub fn dijkstra<N, C, I, F, G>(start: N, neighbours: F, goal: G) -> Option<Found<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    G: FnMut(&N) -> bool,
+{
+    astar(start, neighbours, |_| C::default(), goal)
+}
+
+/// A* search for the lowest cost path from `start` to a node matching `goal`.
+///
+/// The `heuristic` estimates the remaining cost from a node to the goal. It
+/// must never overestimate it for the found path to be the cheapest one.
+pub fn astar<N, C, I, F, H, G>(
+    start: N,
+    mut neighbours: F,
+    mut heuristic: H,
+    mut goal: G,
+) -> Option<Found<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut arena = Arena::new();
+    let mut queue = BinaryHeap::new();
+    let mut expanded = 0;
+
+    let estimate = heuristic(&start);
+
+    if let Some(index) = arena.offer(start, C::default(), None) {
+        queue.push(Reverse((estimate, C::default(), index)));
+    }
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        // Skip stale entries which have since been improved upon.
+        if arena.best[index].0 < cost {
+            continue;
+        }
+
+        if goal(&arena.nodes[index]) {
+            return Some(arena.found(index, expanded));
+        }
+
+        expanded += 1;
+
+        for (next, step) in neighbours(&arena.nodes[index]) {
+            let cost = cost + step;
+            let estimate = cost + heuristic(&next);
+
+            if let Some(next) = arena.offer(next, cost, Some(index)) {
+                queue.push(Reverse((estimate, cost, next)));
+            }
+        }
+    }
+
+    None
+}
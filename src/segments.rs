@@ -0,0 +1,402 @@
+//! Counting of points where line segments on an integer grid overlap.
+//!
+//! Segments are either horizontal, vertical or diagonal at 45 degrees, so
+//! every point they cover has integer coordinates. Overlaps are counted from
+//! the segments themselves, so the time taken doesn't depend on how long they
+//! are.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::Grid;
+
+/// A point on the grid as `(x, y)`.
+pub type Point = (isize, isize);
+
+/// The direction of a [Segment].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    /// Along the x axis. Segments which are a single point are horizontal.
+    Horizontal,
+    /// Along the y axis.
+    Vertical,
+    /// Where `x` and `y` grow together.
+    Diagonal,
+    /// Where `y` shrinks as `x` grows.
+    AntiDiagonal,
+}
+
+impl Direction {
+    const ALL: [Direction; 4] = [
+        Direction::Horizontal,
+        Direction::Vertical,
+        Direction::Diagonal,
+        Direction::AntiDiagonal,
+    ];
+
+    /// The coefficients `(a, b)` of the lines `a * x + b * y = k` which run
+    /// in this direction.
+    fn coefficients(self) -> (isize, isize) {
+        match self {
+            Direction::Horizontal => (0, 1),
+            Direction::Vertical => (1, 0),
+            Direction::Diagonal => (-1, 1),
+            Direction::AntiDiagonal => (1, 1),
+        }
+    }
+
+    /// Convert a point into the constant `k` of the line in this direction
+    /// which goes through it, and the position `t` of the point along it.
+    fn locate(self, (x, y): Point) -> (isize, isize) {
+        let (a, b) = self.coefficients();
+        let t = if self == Direction::Vertical { y } else { x };
+        (a * x + b * y, t)
+    }
+
+    /// The inverse of [Direction::locate].
+    fn point(self, k: isize, t: isize) -> Point {
+        match self {
+            Direction::Horizontal => (t, k),
+            Direction::Vertical => (k, t),
+            Direction::Diagonal => (t, t + k),
+            Direction::AntiDiagonal => (t, k - t),
+        }
+    }
+}
+
+/// A line segment which includes both of its endpoints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Segment {
+    from: Point,
+    to: Point,
+}
+
+impl Segment {
+    /// Construct a segment between two points, or `None` if it is neither
+    /// horizontal, vertical nor diagonal at 45 degrees.
+    pub fn new(from: Point, to: Point) -> Option<Self> {
+        let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+
+        if dx != 0 && dy != 0 && dx.abs() != dy.abs() {
+            return None;
+        }
+
+        Some(Self { from, to })
+    }
+
+    /// The point the segment starts at.
+    pub fn from(&self) -> Point {
+        self.from
+    }
+
+    /// The point the segment ends at.
+    pub fn to(&self) -> Point {
+        self.to
+    }
+
+    /// The direction of the segment.
+    pub fn direction(&self) -> Direction {
+        let (dx, dy) = (self.to.0 - self.from.0, self.to.1 - self.from.1);
+
+        match (dx.signum(), dy.signum()) {
+            (_, 0) => Direction::Horizontal,
+            (0, _) => Direction::Vertical,
+            (a, b) if a == b => Direction::Diagonal,
+            _ => Direction::AntiDiagonal,
+        }
+    }
+
+    /// Test if the segment is diagonal, in either direction.
+    pub fn is_diagonal(&self) -> bool {
+        matches!(
+            self.direction(),
+            Direction::Diagonal | Direction::AntiDiagonal
+        )
+    }
+
+    /// Iterate over every point covered by the segment, from start to end.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let ((dx, dy), len) = self.steps();
+        let from = self.from;
+        (0..=len).map(move |n| (from.0 + dx * n, from.1 + dy * n))
+    }
+
+    /// The step from one point of the segment to the next, and the number of
+    /// steps from start to end.
+    fn steps(&self) -> (Point, isize) {
+        let (dx, dy) = (self.to.0 - self.from.0, self.to.1 - self.from.1);
+        ((dx.signum(), dy.signum()), dx.abs().max(dy.abs()))
+    }
+
+    /// The line the segment lies on, and the range it covers along it.
+    fn span(&self) -> (isize, (isize, isize)) {
+        let direction = self.direction();
+        let (k, a) = direction.locate(self.from);
+        let (_, b) = direction.locate(self.to);
+        (k, (a.min(b), a.max(b)))
+    }
+}
+
+/// Inclusive ranges along a single line.
+type Ranges = Vec<(isize, isize)>;
+
+/// Ranges along every line in one direction, which are covered by at least
+/// one segment and by at least two segments respectively.
+///
+/// Covered lines are ordered by their constant, so that the lines crossing a
+/// range of another direction can be looked up.
+#[derive(Default)]
+struct Coverage {
+    covered: BTreeMap<isize, Ranges>,
+    overlaps: HashMap<isize, Ranges>,
+}
+
+impl Coverage {
+    fn build(spans: HashMap<isize, Ranges>) -> Self {
+        let mut out = Self::default();
+
+        for (k, spans) in spans {
+            let (covered, overlaps) = sweep(&spans);
+            out.covered.insert(k, covered);
+
+            if !overlaps.is_empty() {
+                out.overlaps.insert(k, overlaps);
+            }
+        }
+
+        out
+    }
+
+    /// Test if the given position is covered by at least two segments.
+    fn overlaps(&self, k: isize, t: isize) -> bool {
+        self.overlaps
+            .get(&k)
+            .is_some_and(|ranges| contains(ranges, t))
+    }
+}
+
+/// Sweep over the given ranges along a single line, producing the sorted
+/// disjoint ranges covered at least once and at least twice.
+fn sweep(spans: &[(isize, isize)]) -> (Ranges, Ranges) {
+    let mut events = Vec::with_capacity(spans.len() * 2);
+
+    for &(a, b) in spans {
+        events.push((a, 1i32));
+        events.push((b + 1, -1));
+    }
+
+    events.sort_unstable();
+
+    let mut covered = Vec::new();
+    let mut overlaps = Vec::new();
+    let mut starts = [0; 2];
+    let mut depth = 0;
+    let mut events = events.into_iter().peekable();
+
+    while let Some((pos, mut delta)) = events.next() {
+        while let Some((_, d)) = events.next_if(|(p, _)| *p == pos) {
+            delta += d;
+        }
+
+        let next = depth + delta;
+
+        for (n, out) in [&mut covered, &mut overlaps].into_iter().enumerate() {
+            let level = n as i32 + 1;
+            let start = &mut starts[n];
+
+            if depth < level && next >= level {
+                *start = pos;
+            } else if depth >= level && next < level {
+                out.push((*start, pos - 1));
+            }
+        }
+
+        depth = next;
+    }
+
+    (covered, overlaps)
+}
+
+/// Intersect the lines `a * x + b * y = k` in two different directions,
+/// returning the point where they cross if it has integer coordinates.
+fn intersect(d1: Direction, k1: isize, d2: Direction, k2: isize) -> Option<Point> {
+    let (a1, b1) = d1.coefficients();
+    let (a2, b2) = d2.coefficients();
+
+    let det = a1 * b2 - a2 * b1;
+    let x = k1 * b2 - k2 * b1;
+    let y = a1 * k2 - a2 * k1;
+
+    if det == 0 || x % det != 0 || y % det != 0 {
+        return None;
+    }
+
+    Some((x / det, y / det))
+}
+
+/// Count the points covered by at least two of the given segments, working
+/// with the ranges covered along each line rather than individual points.
+///
+/// Segments on the same line are merged into the ranges which they cover once
+/// and at least twice. Crossings of lines in different directions are found
+/// by looking up, for every covered range, only the lines of the other
+/// direction which pass through it, so lines which never meet aren't tested.
+pub fn overlaps_by_line(segments: &[Segment]) -> usize {
+    let mut spans = HashMap::<_, HashMap<_, Ranges>>::new();
+
+    for segment in segments {
+        let (k, range) = segment.span();
+        spans
+            .entry(segment.direction())
+            .or_default()
+            .entry(k)
+            .or_default()
+            .push(range);
+    }
+
+    let coverage = Direction::ALL
+        .into_iter()
+        .map(|d| Coverage::build(spans.remove(&d).unwrap_or_default()))
+        .collect::<Vec<_>>();
+
+    // Points which are covered in more than one direction.
+    let mut crossings = HashSet::new();
+
+    for (i, d1) in Direction::ALL.into_iter().enumerate() {
+        for (j, d2) in Direction::ALL.into_iter().enumerate().skip(i + 1) {
+            for (&k1, r1) in &coverage[i].covered {
+                for &(a, b) in r1 {
+                    // The constant of lines in the other direction changes
+                    // monotonically along the range.
+                    let (ka, _) = d2.locate(d1.point(k1, a));
+                    let (kb, _) = d2.locate(d1.point(k1, b));
+
+                    for (&k2, r2) in coverage[j].covered.range(ka.min(kb)..=ka.max(kb)) {
+                        let p = match intersect(d1, k1, d2, k2) {
+                            Some(p) => p,
+                            None => continue,
+                        };
+
+                        let (_, t2) = d2.locate(p);
+
+                        if contains(r2, t2) {
+                            crossings.insert(p);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Points which aren't crossings are covered in at most one direction, so
+    // overlaps in different directions can be counted separately.
+    let mut count = crossings.len();
+
+    for (d, coverage) in Direction::ALL.into_iter().zip(&coverage) {
+        let total = coverage
+            .overlaps
+            .values()
+            .flatten()
+            .map(|(a, b)| (b - a + 1) as usize)
+            .sum::<usize>();
+
+        let crossed = crossings
+            .iter()
+            .filter(|p| {
+                let (k, t) = d.locate(**p);
+                coverage.overlaps(k, t)
+            })
+            .count();
+
+        count += total - crossed;
+    }
+
+    count
+}
+
+/// Count the points covered by at least two of the given segments by
+/// visiting every point of every segment.
+///
+/// This takes time and memory proportional to the length of the segments,
+/// but is simple enough to serve as a reference for [overlaps_by_line].
+pub fn overlaps_by_cell(segments: &[Segment]) -> usize {
+    let mut map = HashMap::<_, u32>::new();
+
+    for segment in segments {
+        for p in segment.points() {
+            *map.entry(p).or_default() += 1;
+        }
+    }
+
+    map.values().filter(|n| **n >= 2).count()
+}
+
+/// The top left and bottom right corners of the bounding box of the given
+/// segments, or `None` if there are no segments.
+pub fn bounds(segments: &[Segment]) -> Option<(Point, Point)> {
+    let points = segments.iter().flat_map(|s| [s.from, s.to]);
+    let min = points.clone().reduce(|a, b| (a.0.min(b.0), a.1.min(b.1)))?;
+    let max = points.reduce(|a, b| (a.0.max(b.0), a.1.max(b.1)))?;
+    Some((min, max))
+}
+
+/// Count how many segments cover each point inside of the bounding box of
+/// the given segments, adding up the counts of every square block of points.
+///
+/// Segments are visited one block at a time rather than one point at a time,
+/// so neither the time nor the memory taken depends on the number of points
+/// in a block.
+///
+/// Returns the point at the top left corner of the bounding box, which is the
+/// top left corner of the block at position `(0, 0)` of the grid.
+///
+/// # Panics
+///
+/// Panics if `block` is zero.
+pub fn count_map(segments: &[Segment], block: usize) -> (Point, Grid<u64>) {
+    assert!(block > 0, "block size must be non-zero");
+
+    let ((min_x, min_y), (max_x, max_y)) = bounds(segments).unwrap_or(((0, 0), (-1, -1)));
+
+    let width = ((max_x - min_x + 1) as usize).div_ceil(block);
+    let height = ((max_y - min_y + 1) as usize).div_ceil(block);
+    let mut grid = Grid::filled(width, height, 0u64);
+
+    let size = block as isize;
+
+    // The number of steps which stay inside of the block starting at `start`,
+    // when moving from `pos` in the direction `d`.
+    let stay = |pos: isize, start: isize, d: isize| match d {
+        1 => start + size - 1 - pos,
+        -1 => pos - start,
+        _ => isize::MAX,
+    };
+
+    for segment in segments {
+        let ((dx, dy), len) = segment.steps();
+        let (mut x, mut y) = (segment.from.0 - min_x, segment.from.1 - min_y);
+        let mut n = 0;
+
+        while n <= len {
+            let (bx, by) = (x / size, y / size);
+            let steps = stay(x, bx * size, dx)
+                .min(stay(y, by * size, dy))
+                .min(len - n)
+                + 1;
+
+            let count = &mut grid[(bx as usize, by as usize)];
+            *count = count.saturating_add(steps as u64);
+
+            n += steps;
+            x += dx * steps;
+            y += dy * steps;
+        }
+    }
+
+    ((min_x, min_y), grid)
+}
+
+/// Test if the given sorted disjoint ranges contain a position.
+fn contains(ranges: &[(isize, isize)], t: isize) -> bool {
+    let n = ranges.partition_point(|&(_, end)| end < t);
+    ranges.get(n).is_some_and(|&(start, _)| start <= t)
+}
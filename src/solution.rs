@@ -0,0 +1,23 @@
+use std::fmt;
+
+use anyhow::Result;
+
+/// The solution to a single day.
+///
+/// The input is parsed once through [Solution::parse], after which each part
+/// can be solved independently of the other.
+pub trait Solution: Sized {
+    /// The answer to the first part.
+    type Part1: fmt::Display;
+    /// The answer to the second part.
+    type Part2: fmt::Display;
+
+    /// Parse the input of the day.
+    fn parse(input: &str) -> Result<Self>;
+
+    /// Solve the first part.
+    fn part1(&self) -> Result<Self::Part1>;
+
+    /// Solve the second part.
+    fn part2(&self) -> Result<Self::Part2>;
+}
@@ -0,0 +1,25 @@
+//! Tests for `aoc::bench`.
+
+use std::time::Duration;
+
+use aoc::bench::{self, Bench};
+use aoc::days::Timings;
+
+#[test]
+fn json() {
+    let timings = Timings {
+        parse: Duration::from_nanos(1),
+        part1: Duration::from_nanos(2),
+        part2: Duration::from_nanos(3),
+    };
+
+    let bench = Bench::new("a \"b\"\\c\n", 7, &[timings]).unwrap();
+    let json = bench::to_json(&[bench]);
+
+    assert!(
+        json.contains(r#""profile": "a \"b\"\\c\u000a", "day": 7, "runs": 1"#),
+        "{}",
+        json
+    );
+    assert!(json.contains(r#""total": {"min_ns": 6, "median_ns": 6, "mean_ns": 6}"#));
+}
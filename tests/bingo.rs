@@ -0,0 +1,84 @@
+//! Tests for `aoc::bingo`.
+
+use aoc::bingo::{Bingo, Board, Win};
+use aoc::Grid;
+
+fn board(width: usize, numbers: &[u64]) -> Board {
+    Board::new(Grid::from_vec(width, numbers.to_vec()))
+}
+
+#[test]
+fn parse_board() {
+    let board = aoc::parse::<Board>("22 13 17\n 8  2 23\n").unwrap();
+    assert_eq!((board.grid().width(), board.grid().height()), (3, 2));
+    assert_eq!(board.grid()[(2, 1)], 23);
+
+    let e = aoc::parse::<Board>("1 2 3\n4 5\n").unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "expected a row of 3 numbers at line 2, column 1: `4 5`"
+    );
+}
+
+#[test]
+fn win_order() {
+    // Large numbers, non-square boards and more than 128 boards.
+    let mut boards = (0..200)
+        .map(|n| board(3, &[1000 + n, 2, 3, 4, 5, 6]))
+        .collect::<Vec<_>>();
+    boards.push(board(2, &[7, 2, 8, 9]));
+
+    let bingo = Bingo::new(boards);
+    let wins = bingo.play(&[2, 7, 1005, 3, 1199]);
+
+    assert_eq!(wins.len(), 3);
+    assert_eq!(
+        wins[0],
+        Win {
+            board: 200,
+            draw: 1,
+            number: 7,
+            score: Some(17 * 7),
+        }
+    );
+    assert_eq!(
+        (wins[1].board, wins[1].draw, wins[1].score),
+        (5, 3, Some(15 * 3))
+    );
+    assert_eq!((wins[2].board, wins[2].score), (199, Some(15 * 1199)));
+}
+
+#[test]
+fn diagonals() {
+    let boards = vec![board(3, &[1, 2, 3, 4, 5, 6, 7, 8, 9])];
+    let draws = [3, 5, 7, 1];
+
+    assert!(Bingo::new(boards.clone()).play(&draws).is_empty());
+
+    let wins = Bingo::new(boards).with_diagonals(true).play(&draws);
+    assert_eq!(wins.len(), 1);
+    assert_eq!(
+        (wins[0].draw, wins[0].score),
+        (2, Some((1 + 2 + 4 + 6 + 8 + 9) * 7))
+    );
+}
+
+#[test]
+fn duplicate_numbers() {
+    // Both cells with 4 are marked on the winning draw.
+    let wins = Bingo::new(vec![board(2, &[4, 4, 1, 2])]).play(&[4]);
+    assert_eq!(wins[0].score, Some((1 + 2) * 4));
+}
+
+#[test]
+fn overflowing_scores() {
+    let boards = vec![
+        board(2, &[1, 2, u64::MAX, 3]),
+        board(2, &[4, 5, 6, u64::MAX / 2]),
+    ];
+
+    let wins = Bingo::new(boards).play(&[1, 2, 4, 5, 6]);
+    assert_eq!(wins.len(), 2);
+    assert_eq!(wins[0].score, None);
+    assert_eq!(wins[1].score, None);
+}
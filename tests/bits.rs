@@ -0,0 +1,67 @@
+//! Tests for `aoc::bits`.
+
+use aoc::bits::{BitReader, BitWriter, BitsError};
+
+#[test]
+fn read_hex() {
+    // The literal packet from the day 16 description.
+    let mut r = BitReader::from_hex("D2FE28").unwrap();
+
+    assert_eq!(r.len(), 24);
+    assert_eq!(r.read(3).unwrap(), 6);
+    assert_eq!(r.read(3).unwrap(), 4);
+    assert!(r.read_bit().unwrap());
+    assert_eq!(r.read(4).unwrap(), 0b0111);
+    assert_eq!(r.position(), 11);
+    assert_eq!(r.remaining(), 13);
+
+    r.skip(10).unwrap();
+    assert_eq!(r.read(3).unwrap(), 0);
+    assert!(r.is_empty());
+
+    assert_eq!(
+        r.read(1),
+        Err(BitsError::Eof {
+            bits: 1,
+            pos: 24,
+            remaining: 0
+        })
+    );
+
+    assert_eq!(
+        BitReader::from_hex("12G4").unwrap_err(),
+        BitsError::InvalidHex { c: 'G', index: 2 }
+    );
+}
+
+#[test]
+fn roundtrip() {
+    let values = [(5, 3), (u128::MAX, 128), (0, 7), (0xdead_beef, 32), (1, 1)];
+
+    let mut w = BitWriter::new();
+
+    for (value, bits) in values {
+        w.write(value, bits);
+    }
+
+    assert_eq!(w.len(), 171);
+
+    let mut r = w.reader();
+
+    for (value, bits) in values {
+        assert_eq!(r.read(bits).unwrap(), value);
+    }
+
+    assert!(r.is_empty());
+    assert_eq!(r.read(129), Err(BitsError::TooWide(129)));
+
+    let mut w = BitWriter::new();
+    w.write(0xD2FE28, 24);
+    w.write(0b101, 3);
+    assert_eq!(w.to_hex(), "D2FE28A");
+    assert_eq!(w.as_bytes(), [0xD2, 0xFE, 0x28, 0xA0]);
+    assert_eq!(
+        BitReader::new(w.as_bytes()).read(27).unwrap(),
+        0xD2FE28 << 3 | 0b101
+    );
+}
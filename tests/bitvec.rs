@@ -0,0 +1,76 @@
+//! Tests for `aoc::BitVec` and `aoc::ColumnCounts`.
+
+use aoc::{BitVec, ColumnCounts};
+
+#[test]
+fn parse_and_convert() {
+    let bits = aoc::parse::<BitVec>("10110").unwrap();
+
+    assert_eq!(bits.len(), 5);
+    assert!(bits.get(0));
+    assert!(!bits.get(1));
+    assert_eq!(bits.count_ones(), 3);
+    assert_eq!(bits.to_u64(), Some(22));
+    assert_eq!(bits.not().to_u64(), Some(9));
+    assert_eq!(bits.to_string(), "10110");
+
+    let e = aoc::parse::<BitVec>("1021").unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "expected a binary digit at line 1, column 3: `2`"
+    );
+}
+
+#[test]
+fn wide() {
+    let line = format!("1{}", "0".repeat(99));
+    let bits = aoc::parse::<BitVec>(&line).unwrap();
+
+    assert_eq!(bits.len(), 100);
+    assert_eq!(bits.to_string(), line);
+    assert_eq!(bits.to_u64(), None);
+    assert_eq!(bits.not().count_ones(), 99);
+
+    // Leading zeros don't count towards the width of the value.
+    let bits = aoc::parse::<BitVec>(&format!("{}1", "0".repeat(99))).unwrap();
+    assert_eq!(bits.to_u64(), Some(1));
+}
+
+#[test]
+fn ordering() {
+    let a = aoc::parse::<BitVec>("0111").unwrap();
+    let b = aoc::parse::<BitVec>("1000").unwrap();
+    assert!(a < b);
+}
+
+#[test]
+fn column_counts() {
+    let lines = ["00100", "11110", "10110", "10111", "10101"];
+    let lines = lines
+        .iter()
+        .map(|l| aoc::parse::<BitVec>(l).unwrap())
+        .collect::<Vec<_>>();
+
+    let mut counts = ColumnCounts::new();
+    counts.extend(&lines);
+
+    assert_eq!(counts.total(), 5);
+    assert_eq!(counts.len(), 5);
+
+    let ones = (0..5).map(|n| counts.ones(n)).collect::<Vec<_>>();
+    assert_eq!(ones, [4, 1, 5, 3, 2]);
+    assert_eq!(counts.most_common().to_string(), "10110");
+
+    // Counts which span several words and need many counter planes.
+    let wide = (0..70).map(|n| n % 3 == 0).collect::<BitVec>();
+    let mut counts = ColumnCounts::new();
+
+    for _ in 0..1000 {
+        counts.add(&wide);
+    }
+
+    assert_eq!(counts.ones(0), 1000);
+    assert_eq!(counts.ones(1), 0);
+    assert_eq!(counts.ones(69), 1000);
+    assert_eq!(counts.ones(100), 0);
+}
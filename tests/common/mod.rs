@@ -0,0 +1,21 @@
+//! Helpers shared between tests.
+
+/// A small deterministic xorshift generator.
+pub struct Rng(pub u64);
+
+impl Rng {
+    /// Generate a number in the range `0..n`.
+    pub fn next<T>(&mut self, n: T) -> T
+    where
+        T: TryInto<u64> + TryFrom<u64>,
+    {
+        let n = n
+            .try_into()
+            .unwrap_or_else(|_| panic!("bound must be positive"));
+
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        T::try_from(self.0 % n).unwrap_or_else(|_| panic!("number is out of range"))
+    }
+}
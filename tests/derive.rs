@@ -0,0 +1,79 @@
+//! Tests for `#[derive(Parseable)]`.
+
+use aoc::{Parseable, Parser};
+
+#[derive(Debug, PartialEq, Eq, Parseable)]
+#[parse(sep = ",")]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, PartialEq, Eq, Parseable)]
+enum Dir {
+    #[parse("L")]
+    Left,
+    #[parse("R")]
+    Right,
+}
+
+#[derive(Debug, PartialEq, Eq, Parseable)]
+struct Move<'a> {
+    name: &'a str,
+    dir: Dir,
+    #[parse(lit = "to")]
+    to: Point,
+}
+
+#[derive(Debug, PartialEq, Eq, Parseable)]
+struct Wrapper<T>(#[parse(lit = "wrapped in")] T);
+
+#[test]
+fn parse_struct() {
+    let m = aoc::parse::<Move<'_>>("first R to 10,x").unwrap_err();
+    assert_eq!(
+        m.to_string(),
+        "failed to parse int at line 1, column 15: `x`"
+    );
+
+    let m = aoc::parse::<Move<'_>>("first R to 10,-4").unwrap();
+
+    assert_eq!(
+        m,
+        Move {
+            name: "first",
+            dir: Dir::Right,
+            to: Point { x: 10, y: -4 },
+        }
+    );
+}
+
+#[test]
+fn parse_errors() {
+    let mut p = Parser::new("1,2\nwrapped in 3,4\nwrapped as 5,6");
+
+    assert_eq!(
+        p.line().unwrap().parse::<Point>().unwrap(),
+        Point { x: 1, y: 2 }
+    );
+
+    assert_eq!(
+        p.line().unwrap().parse::<Wrapper<Point>>().unwrap(),
+        Wrapper(Point { x: 3, y: 4 })
+    );
+
+    let e = p.line().unwrap().parse::<Wrapper<Point>>().unwrap_err();
+    assert_eq!(e.to_string(), "expected `in` at line 3, column 9: `as`");
+
+    let e = aoc::parse::<Point>("1,2,garbage").unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "trailing input at line 1, column 5: `garbage`"
+    );
+
+    let e = aoc::parse::<Dir>("U").unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "expected one of `L`, `R` at line 1, column 1: `U`"
+    );
+}
@@ -0,0 +1,108 @@
+//! Runs every day against the examples from the puzzle descriptions.
+//!
+//! Examples are stored in `input/examples/NN-<k>.txt`, and their expected
+//! answers in `input/examples/answers.toml` under the table
+//! `[example-<k>.NN]`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Result};
+use aoc::{Expected, Part};
+
+fn examples_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("input")
+        .join("examples")
+}
+
+/// Run all examples for the given day, returning the number of checked
+/// answers.
+fn check(day: u32) -> Result<usize> {
+    let dir = examples_dir();
+    let expected = Expected::read(&dir.join(aoc::EXPECTED))?;
+    let solution = match aoc::days::get(day) {
+        Some(solution) => solution,
+        None => bail!("day {} is not available", day),
+    };
+
+    let mut checked = 0;
+    let mut errors = Vec::new();
+
+    for k in 1.. {
+        let path = dir.join(format!("{:02}-{}.txt", day, k));
+
+        if !path.is_file() {
+            break;
+        }
+
+        let input = fs::read_to_string(&path)?;
+        let profile = format!("example-{}", k);
+
+        for part in Part::ALL {
+            let expected = match expected.get(&profile, day, part) {
+                Some(expected) => expected,
+                None => continue,
+            };
+
+            checked += 1;
+
+            let answer = match solution.solve_part(&input, part) {
+                Ok(answer) => answer,
+                Err(e) => {
+                    errors.push(format!("{}: {}: error: {:?}", path.display(), part, e));
+                    continue;
+                }
+            };
+
+            if answer != expected {
+                errors.push(format!(
+                    "{}: {}: got {:?}, expected {:?}",
+                    path.display(),
+                    part,
+                    answer,
+                    expected
+                ));
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        bail!("{}", errors.join("\n"));
+    }
+
+    Ok(checked)
+}
+
+macro_rules! examples {
+    ($($name:ident => $day:expr),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() -> Result<()> {
+                assert!(check($day)? > 0, "no examples for day {}", $day);
+                Ok(())
+            }
+        )*
+    };
+}
+
+examples! {
+    day01 => 1,
+    day02 => 2,
+    day03 => 3,
+    day04 => 4,
+    day05 => 5,
+    day06 => 6,
+    day07 => 7,
+    day08 => 8,
+    day09 => 9,
+    day10 => 10,
+    day11 => 11,
+    day12 => 12,
+    day13 => 13,
+    day14 => 14,
+    day15 => 15,
+    day16 => 16,
+    day17 => 17,
+    day18 => 18,
+}
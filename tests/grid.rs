@@ -0,0 +1,47 @@
+//! Tests for `Grid`.
+
+use aoc::{Grid, ParseErrorKind};
+
+#[test]
+fn parse_grid() {
+    let grid = aoc::parse::<Grid<u32>>("123\n456\n\n789").unwrap();
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(2, 1)], 6);
+    assert_eq!(grid.get(3, 0), None);
+    assert_eq!(grid.row(1), [4, 5, 6]);
+    assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+
+    let transposed = grid.transpose();
+    assert_eq!((transposed.width(), transposed.height()), (2, 3));
+    assert_eq!(transposed.row(2), [3, 6]);
+
+    assert_eq!(
+        grid.render(|n| char::from_digit(*n, 10).unwrap()),
+        "123\n456\n"
+    );
+
+    let e = aoc::parse::<Grid<u32>>("123\n4x6").unwrap_err();
+    assert!(matches!(e.kind(), ParseErrorKind::Expected(..)));
+    assert_eq!(e.location().unwrap().column, 2);
+
+    let e = aoc::parse::<Grid<bool>>("#.#\n##").unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "expected a row of width 3 at line 2, column 1: `##`"
+    );
+}
+
+#[test]
+fn neighbours() {
+    let grid = Grid::filled(3, 3, 0u8);
+
+    let mut n = grid.neighbours4(0, 0).collect::<Vec<_>>();
+    n.sort();
+    assert_eq!(n, [(0, 1), (1, 0)]);
+
+    assert_eq!(grid.neighbours4(1, 1).count(), 4);
+    assert_eq!(grid.neighbours8(1, 1).count(), 8);
+    assert_eq!(grid.neighbours8(2, 2).count(), 3);
+    assert_eq!(grid.neighbours8(2, 1).count(), 5);
+}
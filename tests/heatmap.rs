@@ -0,0 +1,37 @@
+//! Tests for `aoc::heatmap`.
+
+use aoc::heatmap::Heatmap;
+use aoc::Grid;
+
+fn heatmap() -> Heatmap {
+    Heatmap::new(Grid::from_vec(3, vec![0, 1, 2, 12, 0, 3]))
+}
+
+#[test]
+fn ascii() {
+    let heatmap = heatmap();
+    assert_eq!(heatmap.max(), 12);
+    assert_eq!(heatmap.to_ascii(), ".12\n+.3\n");
+    assert_eq!(Heatmap::new(Grid::filled(0, 0, 0)).to_ascii(), "");
+}
+
+#[test]
+fn images() {
+    let heatmap = heatmap();
+
+    let pgm = heatmap.to_pgm();
+    let (header, pixels) = pgm.split_at(pgm.len() - 6);
+    assert_eq!(header, b"P5\n3 2\n255\n");
+    assert_eq!(pixels, [0, 21, 42, 255, 0, 63]);
+
+    let ppm = heatmap.to_ppm();
+    let (header, pixels) = ppm.split_at(ppm.len() - 18);
+    assert_eq!(header, b"P6\n3 2\n255\n");
+    assert_eq!(&pixels[..3], [0, 0, 0]);
+    assert_eq!(&pixels[3..6], [0, 0, 255]);
+    assert_eq!(&pixels[9..12], [255, 0, 0]);
+
+    // Nothing to scale against.
+    let empty = Heatmap::new(Grid::filled(2, 1, 0));
+    assert_eq!(&empty.to_pgm()[11..], [0, 0]);
+}
@@ -0,0 +1,38 @@
+//! Tests for `Interner` and its use in day 12.
+
+use aoc::days::day12::Day12;
+use aoc::{Interner, Solution};
+
+#[test]
+fn intern() {
+    let mut names = Interner::new();
+
+    let start = names.intern("start");
+    let a = names.intern("A");
+    assert_eq!(names.intern("start"), start);
+    assert_ne!(start, a);
+
+    assert_eq!(names.len(), 2);
+    assert_eq!(names.get("A"), Some(a));
+    assert_eq!(names.get("b"), None);
+    assert_eq!(names.resolve(a), "A");
+    assert_eq!(a.index(), 1);
+
+    let all = names.iter().collect::<Vec<_>>();
+    assert_eq!(all, [(start, "start"), (a, "A")]);
+}
+
+#[test]
+fn day12_paths() {
+    let input = "start-A\nstart-b\nA-c\nA-b\nb-d\nA-end\nb-end\n";
+    let day = Day12::parse(input).unwrap();
+
+    let mut paths = day.paths(false);
+    paths.sort();
+
+    assert_eq!(paths.len(), 10);
+    assert!(paths.contains(&String::from("start,A,b,A,c,A,end")));
+    assert!(paths.contains(&String::from("start,b,end")));
+
+    assert_eq!(day.paths(true).len(), 36);
+}
@@ -0,0 +1,121 @@
+//! Tests for `aoc::lanternfish` and `aoc::matrix`.
+
+use aoc::lanternfish::{LanternfishError, Model};
+use aoc::matrix::{Checked, Matrix, Modulo};
+
+/// The school from the day 6 example.
+fn example(model: &Model) -> Vec<u64> {
+    model.school([3, 4, 3, 1, 2]).unwrap()
+}
+
+#[test]
+fn matrix_pow() {
+    // Fibonacci numbers.
+    let m = Matrix::from_fn(2, |row, column| u64::from(row + column < 2));
+
+    assert_eq!(*m.pow(0, &Checked).unwrap().get(0, 1), 0);
+    assert_eq!(*m.pow(10, &Checked).unwrap().get(0, 1), 55);
+    let m92 = m.pow(92, &Checked).unwrap();
+    assert_eq!(*m92.get(0, 1), 7540113804746346429);
+    assert_eq!(*m92.get(0, 0), 12200160415121876738);
+    assert!(m.pow(93, &Checked).is_none());
+    assert_eq!(
+        *m.pow(93, &Modulo(1_000_000_007)).unwrap().get(0, 0),
+        686304600
+    );
+
+    assert_eq!(m.mul_vec(&[1, 2], &Checked).unwrap(), [3, 1]);
+}
+
+#[test]
+fn count() {
+    let model = Model::default();
+    let school = example(&model);
+
+    assert_eq!(model.count(&school, 18, &Checked), Ok(26));
+    assert_eq!(model.count(&school, 80, &Checked), Ok(5934));
+    assert_eq!(model.count(&school, 256, &Checked), Ok(26984457539));
+
+    for days in 0..=400 {
+        assert_eq!(
+            model.count(&school, days, &Checked),
+            model.simulate(&school, days),
+            "after {} days",
+            days
+        );
+    }
+
+    assert_eq!(
+        model.count(&school, 500, &Checked),
+        Err(LanternfishError::Overflow)
+    );
+    assert_eq!(
+        model.simulate(&school, 500),
+        Err(LanternfishError::Overflow)
+    );
+
+    // Only feasible in logarithmic time.
+    let p = 1_000_000_007;
+    let n = model.count(&school, 1_000_000_000_000, &Modulo(p)).unwrap();
+    assert!(n < p);
+}
+
+#[test]
+fn timers() {
+    assert_eq!(
+        Model::new(9, 8),
+        Err(LanternfishError::Timers {
+            spawn: 9,
+            newborn: 8
+        })
+    );
+
+    let model = Model::new(2, 4).unwrap();
+    assert_eq!(model.states(), 5);
+    assert_eq!(
+        model.school([1, 5]),
+        Err(LanternfishError::Timer {
+            timer: 5,
+            newborn: 4
+        })
+    );
+
+    // Spawning and newborn fish sharing a timer.
+    let same = Model::new(3, 3).unwrap();
+
+    for model in [model, same] {
+        let school = model.school([3, 1, 2, 3, 0]).unwrap();
+
+        for days in 0..=100 {
+            assert_eq!(
+                model.count(&school, days, &Checked),
+                model.simulate(&school, days)
+            );
+        }
+    }
+
+    assert_eq!(same.count(&[1, 0, 0, 0], 4, &Checked), Ok(2));
+    assert_eq!(same.count(&[1, 0, 0, 0], 8, &Checked), Ok(4));
+
+    let short = LanternfishError::School { len: 3, states: 4 };
+    assert_eq!(same.count(&[1, 0, 0], 4, &Checked), Err(short.clone()));
+    assert_eq!(same.simulate(&[1, 0, 0], 4), Err(short));
+}
+
+#[cfg(feature = "bigint")]
+#[test]
+fn exact() {
+    use aoc::matrix::Exact;
+
+    let model = Model::default();
+    let school = example(&model);
+
+    let exact = model.count(&school, 256, &Exact).unwrap();
+    assert_eq!(exact.to_string(), "26984457539");
+
+    let p = 1_000_000_007;
+    let exact = model.count(&school, 10_000, &Exact).unwrap();
+    let modulo = model.count(&school, 10_000, &Modulo(p)).unwrap();
+    assert!(exact.bits() > 1000);
+    assert_eq!(exact % p, modulo.into());
+}
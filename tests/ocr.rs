@@ -0,0 +1,93 @@
+//! Tests for `aoc::ocr`.
+
+use aoc::ocr::{self, Font, OcrError};
+use aoc::Grid;
+
+#[test]
+fn recognize_small() {
+    let page = aoc::parse::<Grid<bool>>(concat!(
+        "###..###...##..#..#.####.###..#....###..\n",
+        "#..#.#..#.#..#.#.#..#....#..#.#....#..#.\n",
+        "#..#.#..#.#....##...###..###..#....#..#.\n",
+        "###..###..#....#.#..#....#..#.#....###..\n",
+        "#.#..#....#..#.#.#..#....#..#.#....#.#..\n",
+        "#..#.#.....##..#..#.#....###..####.#..#.\n",
+    ))
+    .unwrap();
+
+    assert_eq!(ocr::recognize(&page).unwrap(), "RPCKFBLR");
+}
+
+#[test]
+fn roundtrip() {
+    for (font, text) in [
+        (&Font::SMALL, "ABCEFGHIJKLOPRSUZ"),
+        (&Font::LARGE, "ABCEFGHJKLNPRXZ"),
+    ] {
+        let grid = font.render(text).unwrap();
+        assert_eq!(grid.height(), font.height());
+        assert_eq!(ocr::recognize(&grid).unwrap(), text);
+        assert_eq!(font.recognize(&grid).unwrap(), text);
+
+        // Every letter leading, including ones with a blank first column.
+        for n in 0..text.len() {
+            let rotated = format!("{}{}", &text[n..], &text[..n]);
+            let grid = font.render(&rotated).unwrap();
+            assert_eq!(ocr::recognize(&grid).unwrap(), rotated);
+        }
+    }
+
+    for (font, text) in [
+        (&Font::SMALL, "IA"),
+        (&Font::SMALL, "I"),
+        (&Font::LARGE, "JA"),
+    ] {
+        let grid = font.render(text).unwrap();
+        assert_eq!(ocr::recognize(&grid).unwrap(), text);
+    }
+
+    assert!(Font::SMALL.render("AQ").is_none());
+}
+
+#[test]
+fn margins() {
+    let text = Font::SMALL.render("HI").unwrap();
+    let grid = Grid::from_fn(text.width() + 7, text.height() + 3, |x, y| {
+        x >= 3 && y >= 2 && text.get(x - 3, y - 2).copied().unwrap_or_default()
+    });
+
+    assert_eq!(ocr::recognize(&grid).unwrap(), "HI");
+
+    let text = Font::SMALL.render("IH").unwrap();
+    let grid = Grid::from_fn(text.width() + 3, text.height(), |x, y| {
+        x >= 3 && text.get(x - 3, y).copied().unwrap_or_default()
+    });
+
+    assert_eq!(ocr::recognize(&grid).unwrap(), "IH");
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        ocr::recognize(&Grid::filled(4, 6, false)),
+        Err(OcrError::Empty)
+    );
+
+    // The square drawn by the day 13 example.
+    let square = aoc::parse::<Grid<bool>>("#####\n#...#\n#...#\n#...#\n#####\n").unwrap();
+    assert_eq!(ocr::recognize(&square), Err(OcrError::UnsupportedHeight(5)));
+
+    let mut grid = Font::SMALL.render("AB").unwrap();
+    grid[(6, 2)] = false;
+
+    let e = ocr::recognize(&grid).unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "unrecognised glyph #1 at column 5:\n###..\n#..#.\n#.#..\n#..#.\n#..#.\n###..\n"
+    );
+
+    assert_eq!(
+        Font::LARGE.recognize(&grid),
+        Err(OcrError::UnsupportedHeight(6))
+    );
+}
@@ -0,0 +1,77 @@
+//! Tests for `aoc::optimize`.
+
+mod common;
+
+use std::ops::RangeInclusive;
+
+use aoc::days::day07::{linear, triangular};
+use aoc::optimize;
+
+use common::Rng;
+
+#[test]
+fn statistics() {
+    assert_eq!(optimize::median(&[]), None);
+    assert_eq!(optimize::median(&[1, 2, 7, 9]), Some(2));
+    assert_eq!(optimize::median(&[1, 2, 7]), Some(2));
+
+    assert_eq!(optimize::mean(&[]), None);
+    assert_eq!(optimize::mean(&[1, 2, 7, 9]), Some(4));
+    assert_eq!(optimize::mean(&[-1, -2]), Some(-2));
+}
+
+#[test]
+fn search() {
+    let empty = RangeInclusive::new(1, 0);
+    assert_eq!(optimize::ternary_search(empty.clone(), |n| n), None);
+    assert_eq!(optimize::scan(empty, |n| n), None);
+
+    assert_eq!(optimize::ternary_search(-5..=5, |n| n * n), Some((0, 0)));
+    assert_eq!(optimize::ternary_search(3..=5, |n| n * n), Some((3, 9)));
+    assert_eq!(optimize::ternary_search(-5..=-3, |n| n * n), Some((-3, 9)));
+
+    // Plateaus return the lowest position with the lowest cost.
+    let plateau = |n: i64| (n - 2).max(0) + (-n - 2).max(0);
+    assert_eq!(optimize::ternary_search(-10..=10, plateau), Some((-2, 0)));
+    assert_eq!(optimize::scan(-10..=10, plateau), Some((-2, 0)));
+}
+
+#[test]
+fn crabs() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+
+    for _ in 0..200 {
+        let len = rng.next(20) as usize + 1;
+        let mut pos = (0..len).map(|_| rng.next(100) - 20).collect::<Vec<_>>();
+        pos.sort();
+
+        let range = pos[0]..=pos[len - 1];
+
+        // A cost which weighs crabs differently.
+        let weighted = |at: i64| {
+            pos.iter()
+                .enumerate()
+                .map(|(n, p)| (n as i64 % 3 + 1) * (at - p).pow(2))
+                .sum::<i64>()
+        };
+
+        for cost in [
+            &(|at| linear(&pos, at)) as &dyn Fn(i64) -> i64,
+            &|at| triangular(&pos, at),
+            &weighted,
+        ] {
+            let (_, expected) = optimize::scan(range.clone(), cost).unwrap();
+            let (_, found) = optimize::ternary_search(range.clone(), cost).unwrap();
+            assert_eq!(found, expected, "{:?}", pos);
+        }
+
+        let (_, expected) = optimize::scan(range.clone(), |at| linear(&pos, at)).unwrap();
+        let median = optimize::median(&pos).unwrap();
+        assert_eq!(linear(&pos, median), expected, "{:?}", pos);
+
+        let (_, expected) = optimize::scan(range.clone(), |at| triangular(&pos, at)).unwrap();
+        let mean = optimize::mean(&pos).unwrap();
+        let (_, found) = optimize::scan(mean - 1..=mean + 2, |at| triangular(&pos, at)).unwrap();
+        assert_eq!(found, expected, "{:?}", pos);
+    }
+}
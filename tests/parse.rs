@@ -0,0 +1,85 @@
+//! Tests for `Parser` and the `Parseable` implementations of collections.
+
+use aoc::{Delimited, ParseErrorKind, Parser, Sections};
+
+#[test]
+fn parse_collections() {
+    let mut p = Parser::new("1 2 3\n4\n\n3,4, 3,1,2");
+
+    assert_eq!(p.line().unwrap().parse::<Vec<u32>>().unwrap(), [1, 2, 3]);
+
+    let mut line = p.line().unwrap();
+    assert_eq!(line.parse::<Option<u32>>().unwrap(), Some(4));
+    assert_eq!(line.parse::<Option<u32>>().unwrap(), None);
+
+    assert!(p
+        .line()
+        .unwrap()
+        .parse::<Delimited<u32, ','>>()
+        .unwrap()
+        .is_empty());
+
+    let numbers = p.line().unwrap().parse::<Delimited<u32, ','>>().unwrap();
+    assert_eq!(*numbers, [3, 4, 3, 1, 2]);
+
+    let e = aoc::parse::<Vec<[u32; 0]>>("1 2").unwrap_err();
+    assert!(matches!(e.kind(), ParseErrorKind::NoProgress));
+    assert_eq!(e.location().unwrap().text, "1 2");
+}
+
+#[test]
+fn parse_delimited_errors() {
+    let e = aoc::parse::<Delimited<u32, ','>>("1,2,,4").unwrap_err();
+    assert!(matches!(e.kind(), ParseErrorKind::MissingItem));
+    assert_eq!(e.location().unwrap().column, 5);
+
+    let e = aoc::parse::<Delimited<u32, ';'>>("1;2 3").unwrap_err();
+    assert!(matches!(e.kind(), ParseErrorKind::TrailingInput));
+    assert_eq!(e.location().unwrap().text, "3");
+}
+
+#[test]
+fn parse_sections() {
+    let mut p = Parser::new("\n1,2\n\n3 4\n5\n \n\n6\n");
+    let sections = p.sections().map(Parser::into_str).collect::<Vec<_>>();
+    assert_eq!(sections, ["1,2", "3 4\n5", "6"]);
+
+    let input = "1,2\n\n3 4\n5\n\n6\n";
+
+    let Sections((a, b, c)) =
+        aoc::parse::<Sections<(Delimited<u32, ','>, Vec<u32>, u32)>>(input).unwrap();
+    assert_eq!((&*a, &b[..], c), (&[1, 2][..], &[3, 4, 5][..], 6));
+
+    let e = aoc::parse::<Sections<(Delimited<u32, ','>, Vec<u32>, u32, u32)>>(input).unwrap_err();
+    assert!(matches!(e.kind(), ParseErrorKind::MissingSection));
+
+    let e = aoc::parse::<Sections<(Delimited<u32, ','>, u32)>>(input).unwrap_err();
+    assert!(matches!(e.kind(), ParseErrorKind::TrailingInput));
+    assert_eq!(e.location().unwrap().line, 3);
+}
+
+#[test]
+fn parse_chars() {
+    let mut p = Parser::new("[12,-3]ab 7");
+
+    assert_eq!(p.peek_char(), Some('['));
+    p.expect("[").unwrap();
+    assert_eq!(p.digit().unwrap(), 1);
+    assert_eq!(p.number::<u32>().unwrap(), 2);
+    p.expect(",").unwrap();
+    assert_eq!(p.number::<i32>().unwrap(), -3);
+    assert_eq!(p.next_char(), Some(']'));
+    assert_eq!(p.take_while(|c| c.is_alphabetic()), "ab");
+
+    let e = p.expect(",").unwrap_err();
+    assert_eq!(e.to_string(), "expected `,` at line 1, column 10: ` `");
+
+    let e = p.number::<u32>().unwrap_err();
+    assert!(matches!(e.kind(), ParseErrorKind::Expected(..)));
+    assert_eq!(e.location().unwrap().column, 10);
+
+    assert_eq!(p.take_while(char::is_whitespace), " ");
+    assert_eq!(p.number::<u8>().unwrap(), 7);
+    assert!(p.digit().is_err());
+    assert_eq!(p.next_char(), None);
+}
@@ -0,0 +1,54 @@
+//! Tests for `parse_pattern!`.
+
+use aoc::{parse_pattern, ParseErrorKind, Parser};
+
+#[test]
+fn parse_tuple() {
+    let (a, b, c, d): (u32, u32, u32, u32) =
+        parse_pattern!("{},{} -> {},{}", "0,9 -> 5,9").unwrap();
+    assert_eq!((a, b, c, d), (0, 9, 5, 9));
+
+    let (axis, d): (char, usize) = parse_pattern!("fold along {}={}", "fold along y=7").unwrap();
+    assert_eq!((axis, d), ('y', 7));
+
+    let n: i64 = parse_pattern!("{{{}}}", "{-12}").unwrap();
+    assert_eq!(n, -12);
+}
+
+#[test]
+fn parse_located() {
+    let mut p = Parser::new("x=1\nx=2; y=3");
+    p.line().unwrap();
+
+    let e = parse_pattern!("x={}, y={}", p.line().unwrap())
+        .map(|(_, _): (u32, u32)| ())
+        .unwrap_err();
+
+    assert_eq!(e.location().unwrap().line, 2);
+    assert!(matches!(
+        e.kind(),
+        ParseErrorKind::Literal {
+            literal: ", y=",
+            ..
+        }
+    ));
+
+    let e = parse_pattern!("x={}", "x=1 2")
+        .map(|_: u32| ())
+        .unwrap_err();
+
+    assert!(matches!(
+        e.kind(),
+        ParseErrorKind::Placeholder { index: 1, .. }
+    ));
+    assert_eq!(e.location().unwrap().text, "1 2");
+
+    let e = parse_pattern!("{} -> {}", "AB -> C D")
+        .map(|_: (&str, char)| ())
+        .unwrap_err();
+
+    assert!(matches!(
+        e.kind(),
+        ParseErrorKind::Placeholder { index: 2, .. }
+    ));
+}
@@ -0,0 +1,66 @@
+//! Tests for `aoc::search`.
+
+use aoc::search;
+use aoc::Grid;
+
+const MAZE: &str = "\
+..#....
+.##.##.
+...#...
+.#...#.
+...#...";
+
+fn open(grid: &Grid<bool>, (x, y): (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+    grid.neighbours4(x, y).filter(|&n| !grid[n])
+}
+
+#[test]
+fn bfs_shortest_path() {
+    let maze = aoc::parse::<Grid<bool>>(MAZE).unwrap();
+    let goal = (6, 4);
+
+    let found = search::bfs((0, 0), |&n| open(&maze, n), |&n| n == goal).unwrap();
+
+    assert_eq!(found.cost, 10);
+    assert_eq!(found.path.len(), 11);
+    assert_eq!(found.path.first(), Some(&(0, 0)));
+    assert_eq!(found.path.last(), Some(&goal));
+
+    for w in found.path.windows(2) {
+        assert!(maze.neighbours4(w[0].0, w[0].1).any(|n| n == w[1]));
+    }
+
+    assert!(found.stats.expanded <= found.stats.discovered);
+    assert!(search::bfs((0, 0), |&n| open(&maze, n), |&n| n == (2, 0)).is_none());
+}
+
+#[test]
+fn weighted_paths() {
+    let costs = aoc::parse::<Grid<u32>>("1163\n1381\n2136").unwrap();
+    let goal = (3, 2);
+    let neighbours = |&(x, y): &(usize, usize)| costs.neighbours4(x, y).map(|n| (n, costs[n]));
+
+    let dijkstra = search::dijkstra((0, 0), neighbours, |&n| n == goal).unwrap();
+    assert_eq!(dijkstra.cost, 13);
+    assert_eq!(
+        dijkstra.path.iter().skip(1).map(|&n| costs[n]).sum::<u32>(),
+        dijkstra.cost
+    );
+
+    let heuristic = |&(x, y): &(usize, usize)| (goal.0 - x + goal.1 - y) as u32;
+    let astar = search::astar((0, 0), neighbours, heuristic, |&n| n == goal).unwrap();
+    assert_eq!(astar.cost, dijkstra.cost);
+    assert!(astar.stats.expanded <= dijkstra.stats.expanded);
+}
+
+#[test]
+fn reachable_nodes() {
+    let maze = aoc::parse::<Grid<bool>>(MAZE).unwrap();
+    let nodes = search::reachable((3, 0), |&n| open(&maze, n));
+
+    assert_eq!(nodes.first(), Some(&(3, 0)));
+    assert_eq!(nodes.len(), maze.values().filter(|wall| !**wall).count());
+
+    let nodes = search::reachable((0, 0), |&n| open(&maze, n).filter(|n| n.1 < 1));
+    assert_eq!(nodes, [(0, 0), (1, 0)]);
+}
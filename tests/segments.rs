@@ -0,0 +1,120 @@
+//! Tests for `aoc::segments`.
+
+mod common;
+
+use aoc::segments::{self, Direction, Segment};
+
+use common::Rng;
+
+fn segment(x1: isize, y1: isize, x2: isize, y2: isize) -> Segment {
+    Segment::new((x1, y1), (x2, y2)).unwrap()
+}
+
+#[test]
+fn directions() {
+    assert_eq!(segment(0, 9, 5, 9).direction(), Direction::Horizontal);
+    assert_eq!(segment(7, 0, 7, 4).direction(), Direction::Vertical);
+    assert_eq!(segment(1, 1, 3, 3).direction(), Direction::Diagonal);
+    assert_eq!(segment(9, 7, 7, 9).direction(), Direction::AntiDiagonal);
+    assert_eq!(segment(2, 2, 2, 2).direction(), Direction::Horizontal);
+    assert!(Segment::new((0, 0), (2, 1)).is_none());
+
+    let points = segment(9, 7, 7, 9).points().collect::<Vec<_>>();
+    assert_eq!(points, [(9, 7), (8, 8), (7, 9)]);
+}
+
+#[test]
+fn cross_check() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+
+    for _ in 0..200 {
+        let mut lines = Vec::new();
+
+        for _ in 0..rng.next(30) {
+            let (x, y) = (rng.next(20), rng.next(20));
+            let len = rng.next(10);
+
+            let (dx, dy) = match rng.next(5) {
+                0 => (1, 0),
+                1 => (0, -1),
+                2 => (1, 1),
+                3 => (-1, 1),
+                _ => (0, 0),
+            };
+
+            lines.push(segment(x, y, x + dx * len, y + dy * len));
+        }
+
+        assert_eq!(
+            segments::overlaps_by_line(&lines),
+            segments::overlaps_by_cell(&lines),
+            "{:?}",
+            lines
+        );
+    }
+}
+
+#[test]
+fn long_segments() {
+    let n = 10_000_000;
+
+    let lines = [
+        segment(0, 0, n, 0),
+        segment(n / 2, 0, 2 * n, 0),
+        segment(5, -n, 5, n),
+        segment(-n, -n, n, n),
+        segment(-n, n, n, -n),
+    ];
+
+    // The horizontal overlap, plus the crossings outside of it at (5, 0),
+    // (5, 5), (5, -5) and (0, 0).
+    assert_eq!(segments::overlaps_by_line(&lines), (n / 2 + 1) as usize + 4);
+}
+
+#[test]
+fn many_lines() {
+    let (n, spacing) = (2_000, 1_000_000);
+    let mut lines = Vec::new();
+
+    // A lattice of long lines, where only the first four horizontal and the
+    // first three vertical lines cross each other.
+    for i in 0..n {
+        let at = i * spacing;
+        lines.push(segment(0, at, 3 * spacing, at));
+        lines.push(segment(at + 1, 0, at + 1, 3 * spacing));
+    }
+
+    assert_eq!(segments::overlaps_by_line(&lines), 4 * 3);
+}
+
+#[test]
+fn count_map() {
+    let lines = [
+        segment(2, 1, 4, 1),
+        segment(3, 0, 3, 2),
+        segment(2, 2, 4, 0),
+    ];
+    let render = |grid: &aoc::Grid<u64>| grid.render(|n| char::from_digit(*n as u32, 10).unwrap());
+
+    let (origin, grid) = segments::count_map(&lines, 1);
+    assert_eq!(origin, (2, 0));
+    assert_eq!(render(&grid), "011\n131\n110\n");
+
+    let (origin, grid) = segments::count_map(&lines, 2);
+    assert_eq!(origin, (2, 0));
+    assert_eq!(render(&grid), "52\n20\n");
+
+    let (_, grid) = segments::count_map(&[], 3);
+    assert!(grid.is_empty());
+
+    // Only the blocks are allocated, not every point.
+    let n = 10_000_000;
+    let lines = [segment(0, 0, n, 0), segment(n, n, 0, 0)];
+    let (_, grid) = segments::count_map(&lines, 1_000_000);
+    assert_eq!((grid.width(), grid.height()), (11, 11));
+    assert_eq!(grid[(0, 0)], 2_000_000);
+    assert_eq!(grid[(3, 0)], 1_000_000);
+    assert_eq!(grid[(3, 3)], 1_000_000);
+    assert_eq!(grid[(10, 10)], 1);
+    assert_eq!(grid.values().sum::<u64>(), 2 * (n as u64 + 1));
+}